@@ -0,0 +1,109 @@
+use ::linkedlist::unrolledlist::UnrolledList;
+use ::linkedlist::{doublylinkedlist, linkedlist};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SIZE: u64 = 1_000;
+
+fn bench_push_back(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_back");
+
+    group.bench_function("linkedlist", |b| {
+        b.iter(|| {
+            let mut ll = linkedlist::LinkedList::new();
+            for i in 0..SIZE {
+                ll.push_back(black_box(i));
+            }
+            ll
+        })
+    });
+    group.bench_function("doublylinkedlist", |b| {
+        b.iter(|| {
+            let mut ll = doublylinkedlist::LinkedList::new();
+            for i in 0..SIZE {
+                ll.push_back(black_box(i));
+            }
+            ll
+        })
+    });
+    group.bench_function("unrolledlist", |b| {
+        b.iter(|| {
+            let mut ll = UnrolledList::<_>::new();
+            for i in 0..SIZE {
+                ll.push_back(black_box(i));
+            }
+            ll
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+
+    let ll: linkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("linkedlist", |b| b.iter(|| ll.iter().sum::<u64>()));
+    let dll: doublylinkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("doublylinkedlist", |b| b.iter(|| dll.iter().sum::<u64>()));
+    let ull: UnrolledList<_> = (0..SIZE).collect();
+    group.bench_function("unrolledlist", |b| b.iter(|| ull.iter().sum::<u64>()));
+
+    group.finish();
+}
+
+fn bench_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("index");
+
+    let ll: linkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("linkedlist", |b| {
+        b.iter(|| *ll.get_value(black_box(SIZE as usize / 2)).unwrap())
+    });
+    let dll: doublylinkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("doublylinkedlist", |b| {
+        b.iter(|| dll.get(black_box(SIZE as usize / 2)).unwrap())
+    });
+    let ull: UnrolledList<_> = (0..SIZE).collect();
+    group.bench_function("unrolledlist", |b| {
+        b.iter(|| *ull.get_value(black_box(SIZE as usize / 2)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn bench_push_pop_middle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop_middle");
+    let index = SIZE as usize / 2;
+
+    let mut ll: linkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("linkedlist", |b| {
+        b.iter(|| {
+            ll.push(black_box(index), 0).unwrap();
+            ll.pop(black_box(index)).unwrap()
+        })
+    });
+    let mut dll: doublylinkedlist::LinkedList<_> = (0..SIZE).collect();
+    group.bench_function("doublylinkedlist", |b| {
+        b.iter(|| {
+            dll.push(black_box(index), 0).unwrap();
+            dll.pop(black_box(index)).unwrap()
+        })
+    });
+    let mut ull: UnrolledList<_> = (0..SIZE).collect();
+    group.bench_function("unrolledlist", |b| {
+        b.iter(|| {
+            ull.push(black_box(index), 0).unwrap();
+            ull.pop(black_box(index)).unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_push_back,
+    bench_iterate,
+    bench_index,
+    bench_push_pop_middle
+);
+criterion_main!(benches);
@@ -0,0 +1,583 @@
+#![allow(dead_code)]
+
+use allocator_api2::alloc::Allocator;
+use allocator_api2::vec::Vec;
+
+/// A node in an arena-backed linked list.
+#[derive(Clone)]
+pub(crate) struct Node<T> {
+    /// The node's value.
+    pub(crate) value: T,
+    /// The slot holding the previous node in the linked list.
+    pub(crate) prev: Option<usize>,
+    /// The slot holding the next node in the linked list.
+    pub(crate) next: Option<usize>,
+}
+
+/// A storage slot in an arena.
+#[derive(Clone)]
+pub(crate) enum Slot<T> {
+    /// An unused slot, linking to the next unused slot.
+    Free(Option<usize>),
+    /// A slot holding a node.
+    Used(Node<T>),
+}
+
+/// The storage holding the slots of an arena.
+pub(crate) trait Storage {
+    /// The type of the values in the nodes.
+    type Value;
+
+    /// Get the slots.
+    fn slots(&self) -> &[Slot<Self::Value>];
+
+    /// Get the slots mutably.
+    fn slots_mut(&mut self) -> &mut [Slot<Self::Value>];
+
+    /// Add a slot after the existing ones, returning its index, or handing it
+    /// back if there is no room.
+    fn push(&mut self, slot: Slot<Self::Value>) -> Result<usize, Slot<Self::Value>>;
+
+    /// Drop every node, returning the first unused slot.
+    fn reset(&mut self) -> Option<usize>;
+}
+
+impl<T, A: Allocator> Storage for Vec<Slot<T>, A> {
+    type Value = T;
+
+    fn slots(&self) -> &[Slot<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        self
+    }
+
+    fn push(&mut self, slot: Slot<T>) -> Result<usize, Slot<T>> {
+        Vec::push(self, slot);
+
+        Ok(self.len() - 1)
+    }
+
+    fn reset(&mut self) -> Option<usize> {
+        self.clear();
+
+        None
+    }
+}
+
+impl<T, const N: usize> Storage for [Slot<T>; N] {
+    type Value = T;
+
+    fn slots(&self) -> &[Slot<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        self
+    }
+
+    fn push(&mut self, slot: Slot<T>) -> Result<usize, Slot<T>> {
+        Err(slot)
+    }
+
+    fn reset(&mut self) -> Option<usize> {
+        for (i, slot) in self.iter_mut().enumerate() {
+            *slot = Slot::Free((i + 1 < N).then_some(i + 1));
+        }
+
+        (N > 0).then_some(0)
+    }
+}
+
+/// A doubly linked list of nodes stored in reusable slots. Removed nodes
+/// return their slot to a free list, which later insertions take from before
+/// asking the storage for a new slot.
+#[derive(Clone)]
+pub(crate) struct Arena<S> {
+    /// The node slots.
+    slots: S,
+    /// The slot holding the first node.
+    head: Option<usize>,
+    /// The slot holding the last node.
+    tail: Option<usize>,
+    /// The first unused slot.
+    free: Option<usize>,
+    /// The total number of nodes in the linked list.
+    size: usize,
+}
+
+impl<S: Storage> Arena<S> {
+    /// Create an empty linked list, dropping any nodes in `slots`.
+    pub(crate) fn new(mut slots: S) -> Self {
+        let free = slots.reset();
+
+        Self {
+            slots,
+            head: None,
+            tail: None,
+            free,
+            size: 0,
+        }
+    }
+
+    /// Get the number of nodes in the linked list.
+    pub(crate) fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Get the slot holding the first node.
+    pub(crate) fn head(&self) -> Option<usize> {
+        self.head
+    }
+
+    /// Get the slot holding the last node.
+    pub(crate) fn tail(&self) -> Option<usize> {
+        self.tail
+    }
+
+    /// Get the storage holding the slots.
+    pub(crate) fn storage(&self) -> &S {
+        &self.slots
+    }
+
+    /// Get the node in a slot, if the slot exists and is used.
+    pub(crate) fn get(&self, slot: usize) -> Option<&Node<S::Value>> {
+        match self.slots.slots().get(slot) {
+            Some(Slot::Used(node)) => Some(node),
+            _ => None,
+        }
+    }
+
+    /// Get a reference to the node in a used slot.
+    pub(crate) fn node(&self, slot: usize) -> &Node<S::Value> {
+        match &self.slots.slots()[slot] {
+            Slot::Used(node) => node,
+            Slot::Free(_) => unreachable!("linked list refers to an unused slot"),
+        }
+    }
+
+    /// Get a mutable reference to the node in a used slot.
+    pub(crate) fn node_mut(&mut self, slot: usize) -> &mut Node<S::Value> {
+        match &mut self.slots.slots_mut()[slot] {
+            Slot::Used(node) => node,
+            Slot::Free(_) => unreachable!("linked list refers to an unused slot"),
+        }
+    }
+
+    /// Get mutable references to the values in two different used slots.
+    pub(crate) fn values_mut(&mut self, a: usize, b: usize) -> (&mut S::Value, &mut S::Value) {
+        let (low, high) = self.slots.slots_mut().split_at_mut(a.max(b));
+        let (a_slot, b_slot) = if a < b {
+            (&mut low[a], &mut high[0])
+        } else {
+            (&mut high[0], &mut low[b])
+        };
+
+        match (a_slot, b_slot) {
+            (Slot::Used(a), Slot::Used(b)) => (&mut a.value, &mut b.value),
+            _ => unreachable!("linked list refers to an unused slot"),
+        }
+    }
+
+    /// Get the slot holding the node at a given index, walking from whichever
+    /// end of the linked list is nearer.
+    pub(crate) fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.size {
+            return None;
+        }
+
+        if index < self.size / 2 {
+            let mut current = self.head.unwrap();
+
+            for _ in 0..index {
+                current = self.node(current).next.unwrap();
+            }
+
+            Some(current)
+        } else {
+            let mut current = self.tail.unwrap();
+
+            for _ in index + 1..self.size {
+                current = self.node(current).prev.unwrap();
+            }
+
+            Some(current)
+        }
+    }
+
+    /// Store a value in a new node, reusing an unused slot if there is one,
+    /// and link it between two nodes. Hands the value back if the storage is
+    /// full.
+    pub(crate) fn try_link(
+        &mut self,
+        value: S::Value,
+        prev: Option<usize>,
+        next: Option<usize>,
+    ) -> Result<usize, S::Value> {
+        let node = Slot::Used(Node {
+            value,
+            prev: None,
+            next: None,
+        });
+
+        let slot = match self.free {
+            Some(slot) => {
+                match core::mem::replace(&mut self.slots.slots_mut()[slot], node) {
+                    Slot::Free(next_free) => self.free = next_free,
+                    Slot::Used(_) => unreachable!("unused slots refer to a used slot"),
+                }
+
+                slot
+            }
+            None => match self.slots.push(node) {
+                Ok(slot) => slot,
+                Err(Slot::Used(node)) => return Err(node.value),
+                Err(Slot::Free(_)) => unreachable!("storage handed back an unused slot"),
+            },
+        };
+
+        self.attach(slot, prev, next);
+
+        Ok(slot)
+    }
+
+    /// Unlink the node in a slot and free the slot, returning the node's owned
+    /// value.
+    pub(crate) fn unlink(&mut self, slot: usize) -> S::Value {
+        self.detach(slot);
+
+        match core::mem::replace(&mut self.slots.slots_mut()[slot], Slot::Free(self.free)) {
+            Slot::Used(node) => {
+                self.free = Some(slot);
+
+                node.value
+            }
+            Slot::Free(_) => unreachable!("linked list refers to an unused slot"),
+        }
+    }
+
+    /// Link the detached node in a slot between two nodes.
+    pub(crate) fn attach(&mut self, slot: usize, prev: Option<usize>, next: Option<usize>) {
+        let node = self.node_mut(slot);
+        node.prev = prev;
+        node.next = next;
+
+        match prev {
+            Some(prev) => self.node_mut(prev).next = Some(slot),
+            None => self.head = Some(slot),
+        }
+
+        match next {
+            Some(next) => self.node_mut(next).prev = Some(slot),
+            None => self.tail = Some(slot),
+        }
+
+        self.size += 1;
+    }
+
+    /// Unlink the node in a slot from its neighbors, joining them, but keep
+    /// the slot.
+    pub(crate) fn detach(&mut self, slot: usize) {
+        let node = self.node_mut(slot);
+        let (prev, next) = (node.prev.take(), node.next.take());
+
+        match prev {
+            Some(prev) => self.node_mut(prev).next = next,
+            None => self.head = next,
+        }
+
+        match next {
+            Some(next) => self.node_mut(next).prev = prev,
+            None => self.tail = prev,
+        }
+
+        self.size -= 1;
+    }
+
+    /// Drop every node, keeping any slots the storage has allocated.
+    pub(crate) fn clear(&mut self) {
+        self.free = self.slots.reset();
+        self.head = None;
+        self.tail = None;
+        self.size = 0;
+    }
+
+    /// Reverse the nodes in place by swapping their links.
+    pub(crate) fn reverse(&mut self) {
+        for slot in self.slots.slots_mut() {
+            if let Slot::Used(node) = slot {
+                core::mem::swap(&mut node.prev, &mut node.next);
+            }
+        }
+
+        core::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Returns an iterator over the slots holding the nodes, in list order.
+    pub(crate) fn slots_in_order(&self) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.head, |&slot| self.node(slot).next)
+    }
+
+    /// Returns an iterator over the values, in list order.
+    pub(crate) fn iter(&self) -> Iter<'_, S> {
+        Iter {
+            arena: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.size,
+        }
+    }
+
+    /// Move the nodes into list order in the first slots, so that they can be
+    /// walked without following links, and free the slots after them. Takes
+    /// linear time and does not allocate. Nodes change slots, so any slot
+    /// held outside the arena is invalidated.
+    pub(crate) fn compact(&mut self) {
+        // Replace each node's next link with its index in the list, then
+        // swap every node into the slot at its index.
+        let mut current = self.head;
+        let mut index = 0;
+
+        while let Some(slot) = current {
+            current = self.node_mut(slot).next.replace(index);
+            index += 1;
+        }
+
+        let slots = self.slots.slots_mut();
+
+        for slot in 0..slots.len() {
+            while let Slot::Used(Node {
+                next: Some(index), ..
+            }) = slots[slot]
+            {
+                if index == slot {
+                    break;
+                }
+
+                slots.swap(slot, index);
+            }
+        }
+
+        let len = slots.len();
+
+        for (slot, entry) in slots.iter_mut().enumerate() {
+            let next = (slot + 1 < len).then_some(slot + 1);
+
+            match entry {
+                Slot::Used(node) => {
+                    node.prev = slot.checked_sub(1);
+                    node.next = next.filter(|&next| next < self.size);
+                }
+                Slot::Free(next_free) => *next_free = next,
+            }
+        }
+
+        self.head = (self.size > 0).then_some(0);
+        self.tail = self.size.checked_sub(1);
+        self.free = (self.size < len).then_some(self.size);
+    }
+
+    /// Returns an iterator over the values in list order, allowing for them to
+    /// be mutated. The arena is compacted first, so that the iterator can walk
+    /// the slots directly.
+    pub(crate) fn iter_mut(&mut self) -> IterMut<'_, S::Value> {
+        self.compact();
+
+        IterMut(self.slots.slots_mut()[..self.size].iter_mut())
+    }
+
+    /// Merge two chains of nodes linked only by `next`, returning the slot of
+    /// the merged chain's first node. On ties, nodes from `a` come first.
+    pub(crate) fn merge_chains<F: FnMut(&S::Value, &S::Value) -> bool>(
+        &mut self,
+        mut a: Option<usize>,
+        mut b: Option<usize>,
+        is_less: &mut F,
+    ) -> Option<usize> {
+        let mut head = None;
+        let mut tail: Option<usize> = None;
+
+        loop {
+            let slot = match (a, b) {
+                (Some(x), Some(y)) => {
+                    if is_less(&self.node(y).value, &self.node(x).value) {
+                        b = self.node(y).next;
+                        y
+                    } else {
+                        a = self.node(x).next;
+                        x
+                    }
+                }
+                (rest, None) | (None, rest) => {
+                    match tail {
+                        Some(tail) => self.node_mut(tail).next = rest,
+                        None => head = rest,
+                    }
+
+                    return head;
+                }
+            };
+
+            match tail {
+                Some(tail) => self.node_mut(tail).next = Some(slot),
+                None => head = Some(slot),
+            }
+
+            tail = Some(slot);
+        }
+    }
+
+    /// Stably merge sort a chain of `len` nodes linked only by `next`,
+    /// returning the slot of the sorted chain's first node.
+    pub(crate) fn sort_chain<F: FnMut(&S::Value, &S::Value) -> bool>(
+        &mut self,
+        head: usize,
+        len: usize,
+        is_less: &mut F,
+    ) -> usize {
+        if len <= 1 {
+            return head;
+        }
+
+        let mut last = head;
+
+        for _ in 1..len / 2 {
+            last = self.node(last).next.unwrap();
+        }
+
+        let back = self.node_mut(last).next.take().unwrap();
+        let front = self.sort_chain(head, len / 2, is_less);
+        let back = self.sort_chain(back, len - len / 2, is_less);
+
+        self.merge_chains(Some(front), Some(back), is_less).unwrap()
+    }
+
+    /// Make a chain of nodes linked by `next` the whole linked list, setting
+    /// each node's `prev` link and the tail.
+    pub(crate) fn relink_prev(&mut self, head: Option<usize>) {
+        let mut prev = None;
+        let mut current = head;
+
+        while let Some(slot) = current {
+            let node = self.node_mut(slot);
+            node.prev = prev;
+            prev = Some(slot);
+            current = node.next;
+        }
+
+        self.head = head;
+        self.tail = prev;
+    }
+}
+
+impl<T, A: Allocator> Arena<Vec<Slot<T>, A>> {
+    /// Store a value in a new node, reusing an unused slot if there is one or
+    /// growing the storage otherwise, and link it between two nodes.
+    pub(crate) fn link(&mut self, value: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        match self.try_link(value, prev, next) {
+            Ok(slot) => slot,
+            Err(_) => unreachable!("vector storage cannot be full"),
+        }
+    }
+
+    /// Get the number of nodes the storage can hold without allocating.
+    pub(crate) fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Reserve space for at least `additional` more nodes, counting unused
+    /// slots.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        let unused = self.slots.len() - self.size;
+        self.slots.reserve(additional.saturating_sub(unused));
+    }
+
+    /// Compact the arena and release every unused slot.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.compact();
+        self.slots.truncate(self.size);
+        self.slots.shrink_to_fit();
+        self.free = None;
+    }
+}
+
+/// An iterator over the values in an arena, in list order.
+pub(crate) struct Iter<'a, S> {
+    /// The arena being iterated over.
+    arena: &'a Arena<S>,
+    /// The slot holding the next node from the front.
+    front: Option<usize>,
+    /// The slot holding the next node from the back.
+    back: Option<usize>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, S: Storage> Iterator for Iter<'a, S> {
+    type Item = &'a S::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.arena.node(self.front?);
+        self.front = node.next;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<S: Storage> DoubleEndedIterator for Iter<'_, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.arena.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<S: Storage> ExactSizeIterator for Iter<'_, S> {}
+
+/// An iterator over the values in a compacted arena, allowing for them to be
+/// mutated.
+pub(crate) struct IterMut<'a, T>(core::slice::IterMut<'a, Slot<T>>);
+
+/// Get a mutable reference to the value in a slot of a compacted arena.
+fn used_value<T>(slot: &mut Slot<T>) -> &mut T {
+    match slot {
+        Slot::Used(node) => &mut node.value,
+        Slot::Free(_) => unreachable!("compacted linked list has an unused slot"),
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(used_value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(used_value)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
@@ -0,0 +1,296 @@
+use crate::pooledlist::PooledLinkedList;
+use futures::{Sink, Stream};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+/// Async queue errors.
+#[derive(PartialEq, Eq)]
+pub enum QueueError {
+    Full,
+    Disconnected,
+}
+
+impl fmt::Debug for QueueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "sending on a full queue"),
+            Self::Disconnected => write!(f, "sending on a disconnected queue"),
+        }
+    }
+}
+
+/// The state shared between the ends of a queue.
+struct State<T> {
+    /// The queued values, oldest first.
+    queue: PooledLinkedList<T>,
+    /// The maximum number of queued values, if bounded.
+    capacity: Option<usize>,
+    /// The number of slots reserved by senders that are ready to send.
+    reserved: usize,
+    /// The number of senders that are neither dropped nor closed.
+    senders: usize,
+    /// Whether the receiver is still alive.
+    receiver: bool,
+    /// The waker of a receiver waiting for a value.
+    recv_waker: Option<Waker>,
+    /// The wakers of senders waiting for room in the queue.
+    send_wakers: Vec<Waker>,
+}
+
+impl<T> State<T> {
+    /// Check if the queue cannot take another value, counting the slots
+    /// already reserved by ready senders.
+    fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.queue.len() + self.reserved >= capacity)
+    }
+
+    /// Give back a sender's reserved slot, if it holds one, returning
+    /// whether it did.
+    fn release(&mut self, reserved: &mut bool) -> bool {
+        if std::mem::take(reserved) {
+            self.reserved -= 1;
+            return true;
+        }
+
+        false
+    }
+
+    /// Wake the receiver, if it is waiting.
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.recv_waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Wake every sender waiting for room.
+    fn wake_senders(&mut self) {
+        for waker in self.send_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// Lock the queue state.
+fn lock<T>(shared: &Mutex<State<T>>) -> MutexGuard<'_, State<T>> {
+    shared.lock().unwrap()
+}
+
+/// Create an unbounded async queue, returning its sending and receiving ends.
+pub fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    with_capacity(None)
+}
+
+/// Create an async queue holding at most `capacity` values. Senders wait
+/// while the queue is full.
+///
+/// Panics if `capacity` is 0.
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "queue capacity must be nonzero");
+    with_capacity(Some(capacity))
+}
+
+/// Create an async queue with an optional capacity.
+fn with_capacity<T>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let queue = match capacity {
+        Some(capacity) => PooledLinkedList::with_capacity(capacity),
+        None => PooledLinkedList::new(),
+    };
+    let shared = Arc::new(Mutex::new(State {
+        queue,
+        capacity,
+        reserved: 0,
+        senders: 1,
+        receiver: true,
+        recv_waker: None,
+        send_wakers: Vec::new(),
+    }));
+
+    (
+        Sender {
+            shared: shared.clone(),
+            closed: false,
+            reserved: false,
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending end of an async queue, used as a `Sink`. It can be cloned to
+/// send from several tasks. The receiving stream ends once every sender has
+/// been closed or dropped.
+pub struct Sender<T> {
+    shared: Arc<Mutex<State<T>>>,
+    /// Whether this sender has been closed.
+    closed: bool,
+    /// Whether this sender holds a slot reserved by `poll_ready`.
+    reserved: bool,
+}
+
+impl<T> Sender<T> {
+    /// Stop this sender, ending the stream if it was the last one.
+    fn close(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        self.closed = true;
+        let mut state = lock(&self.shared);
+        state.senders -= 1;
+
+        if state.release(&mut self.reserved) {
+            state.wake_senders();
+        }
+
+        if state.senders == 0 {
+            state.wake_receiver();
+        }
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = QueueError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let mut state = lock(&this.shared);
+
+        if this.closed || !state.receiver {
+            return Poll::Ready(Err(QueueError::Disconnected));
+        }
+
+        if this.reserved {
+            return Poll::Ready(Ok(()));
+        }
+
+        if state.is_full() {
+            if !state
+                .send_wakers
+                .iter()
+                .any(|waker| waker.will_wake(cx.waker()))
+            {
+                state.send_wakers.push(cx.waker().clone());
+            }
+
+            return Poll::Pending;
+        }
+
+        state.reserved += 1;
+        this.reserved = true;
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Queue a value. After `poll_ready` has returned `Ready(Ok(()))`, the
+    /// value goes into the slot it reserved, so this only fails if the queue
+    /// has been disconnected since.
+    fn start_send(self: Pin<&mut Self>, value: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        let mut state = lock(&this.shared);
+        let reserved = state.release(&mut this.reserved);
+
+        if this.closed || !state.receiver {
+            return Err(QueueError::Disconnected);
+        }
+
+        if !reserved && state.is_full() {
+            return Err(QueueError::Full);
+        }
+
+        state.queue.push_back(value);
+        state.wake_receiver();
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        lock(&self.shared).senders += 1;
+
+        Self {
+            shared: self.shared.clone(),
+            closed: false,
+            reserved: false,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender")
+            .field("closed", &self.closed)
+            .field("reserved", &self.reserved)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The receiving end of an async queue, used as a `Stream`.
+pub struct Receiver<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = lock(&self.shared);
+
+        if let Ok(value) = state.queue.pop_front() {
+            state.wake_senders();
+            return Poll::Ready(Some(value));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        state.recv_waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let state = lock(&self.shared);
+
+        if state.senders == 0 {
+            (state.queue.len(), Some(state.queue.len()))
+        } else {
+            (state.queue.len(), None)
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = lock(&self.shared);
+        state.receiver = false;
+        state.wake_senders();
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,190 @@
+use crate::doublylinkedlist::{self, LinkedList, LinkedListError};
+use std::iter::{IntoIterator, Rev};
+
+/// What a bounded list does when a value is pushed while it is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Refuse the new value with an error.
+    Reject,
+    /// Evict the oldest value from the front to make room.
+    DropOldest,
+    /// Evict the newest value from the back to make room.
+    DropNewest,
+}
+
+/// A linked list holding at most `max_len` values, ordered from oldest at
+/// the front to newest at the back.
+#[derive(Debug)]
+pub struct BoundedList<T> {
+    /// The values, oldest first.
+    list: LinkedList<T>,
+    /// The maximum number of values.
+    max_len: usize,
+    /// What to do when a value is pushed while the list is full.
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedList<T> {
+    /// Create an empty bounded list holding at most `max_len` values.
+    pub fn new(max_len: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            list: LinkedList::new(),
+            max_len,
+            policy,
+        }
+    }
+
+    /// Get the number of values in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Check if the list contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Check if the list holds `max_len` values.
+    pub fn is_full(&self) -> bool {
+        self.list.len() >= self.max_len
+    }
+
+    /// Get the maximum number of values in the list.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Get the overflow policy.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Set the overflow policy.
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
+    /// Push a value as the newest in the list. If the list is full, the
+    /// overflow policy decides what happens: `Reject` returns an error along
+    /// with the pushed value, and the other policies return the evicted
+    /// value. With a `max_len` of 0, `DropOldest` and `DropNewest` return the
+    /// pushed value itself.
+    pub fn push(&mut self, value: T) -> Result<Option<T>, (LinkedListError, T)> {
+        if !self.is_full() {
+            self.list.push_back(value);
+            return Ok(None);
+        }
+
+        match self.policy {
+            OverflowPolicy::Reject => Err((LinkedListError::CapacityExceeded, value)),
+            _ if self.max_len == 0 => Ok(Some(value)),
+            OverflowPolicy::DropOldest => {
+                let evicted = self.list.pop_front().ok();
+                self.list.push_back(value);
+                Ok(evicted)
+            }
+            OverflowPolicy::DropNewest => {
+                let evicted = self.list.pop_back().ok();
+                self.list.push_back(value);
+                Ok(evicted)
+            }
+        }
+    }
+
+    /// Get a reference to the oldest value.
+    pub fn oldest(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Get a reference to the newest value.
+    pub fn newest(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Remove the oldest value, returning it.
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        self.list.pop_front().ok()
+    }
+
+    /// Remove the newest value, returning it.
+    pub fn pop_newest(&mut self) -> Option<T> {
+        self.list.pop_back().ok()
+    }
+
+    /// Change the maximum number of values, returning the values evicted to
+    /// fit, in eviction order. Values are evicted from the back under
+    /// `DropNewest` and from the front otherwise.
+    pub fn set_max_len(&mut self, max_len: usize) -> Vec<T> {
+        let mut evicted = Vec::new();
+
+        while self.list.len() > max_len {
+            let value = match self.policy {
+                OverflowPolicy::DropNewest => self.list.pop_back(),
+                _ => self.list.pop_front(),
+            };
+            evicted.extend(value.ok());
+        }
+
+        self.max_len = max_len;
+
+        evicted
+    }
+
+    /// Clear the list.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    /// Returns an iterator over the values from oldest to newest.
+    pub fn iter(&self) -> doublylinkedlist::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator over the values from newest to oldest.
+    pub fn iter_newest(&self) -> Rev<doublylinkedlist::Iter<'_, T>> {
+        self.list.iter().rev()
+    }
+}
+
+impl<T: Clone> Clone for BoundedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            max_len: self.max_len,
+            policy: self.policy,
+        }
+    }
+}
+
+impl<T> IntoIterator for BoundedList<T> {
+    type Item = T;
+    type IntoIter = doublylinkedlist::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<BoundedList<U>> for BoundedList<T> {
+    fn eq(&self, other: &BoundedList<U>) -> bool {
+        self.list.eq_by(&other.list, |a, b| *a == *b)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for BoundedList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.list.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for BoundedList<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for BoundedList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
@@ -0,0 +1,345 @@
+use crate::pooledlist::PooledLinkedList;
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// An error returned when sending on a channel whose receiver has been
+/// dropped. The unsent value is handed back.
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a disconnected channel")
+    }
+}
+
+/// An error returned by `Sender::try_send`. The unsent value is handed back.
+pub enum TrySendError<T> {
+    Full(T),
+    Disconnected(T),
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "sending on a full channel"),
+            Self::Disconnected(_) => write!(f, "sending on a disconnected channel"),
+        }
+    }
+}
+
+/// Channel receive errors.
+#[derive(PartialEq, Eq)]
+pub enum RecvError {
+    Empty,
+    Timeout,
+    Disconnected,
+}
+
+impl fmt::Debug for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "receiving on an empty channel"),
+            Self::Timeout => write!(f, "timed out waiting on channel"),
+            Self::Disconnected => write!(f, "receiving on an empty and disconnected channel"),
+        }
+    }
+}
+
+/// The state of a channel guarded by its mutex.
+struct State<T> {
+    /// The queued values, oldest first.
+    queue: PooledLinkedList<T>,
+    /// The number of live senders.
+    senders: usize,
+    /// Whether the receiver is still alive.
+    receiver: bool,
+}
+
+/// The state shared between the ends of a channel.
+struct Shared<T> {
+    /// The guarded channel state.
+    state: Mutex<State<T>>,
+    /// Signalled when a value is queued or the last sender drops.
+    not_empty: Condvar,
+    /// Signalled when a value is dequeued or the receiver drops.
+    not_full: Condvar,
+    /// The maximum number of queued values, if bounded.
+    capacity: Option<usize>,
+}
+
+impl<T> Shared<T> {
+    /// Lock the channel state.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap()
+    }
+
+    /// Check if the queue cannot take another value.
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.capacity
+            .is_some_and(|capacity| state.queue.len() >= capacity)
+    }
+}
+
+/// Create an unbounded channel, returning its sending and receiving ends.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    with_capacity(None)
+}
+
+/// Create a bounded channel holding at most `capacity` queued values.
+/// Sending blocks while the channel is full.
+///
+/// Panics if `capacity` is 0.
+pub fn sync_channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "channel capacity must be nonzero");
+    with_capacity(Some(capacity))
+}
+
+/// Create a channel with an optional capacity.
+fn with_capacity<T>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let queue = match capacity {
+        Some(capacity) => PooledLinkedList::with_capacity(capacity),
+        None => PooledLinkedList::new(),
+    };
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue,
+            senders: 1,
+            receiver: true,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending end of a channel. It can be cloned to send from several
+/// threads.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Send a value, blocking while the channel is full. Fails if the
+    /// receiver has been dropped.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+
+        while state.receiver && self.shared.is_full(&state) {
+            state = self.shared.not_full.wait(state).unwrap();
+        }
+
+        if !state.receiver {
+            return Err(SendError(value));
+        }
+
+        state.queue.push_back(value);
+        self.shared.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Send a value without blocking. Fails if the channel is full or the
+    /// receiver has been dropped.
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+
+        if !state.receiver {
+            return Err(TrySendError::Disconnected(value));
+        }
+
+        if self.shared.is_full(&state) {
+            return Err(TrySendError::Full(value));
+        }
+
+        state.queue.push_back(value);
+        self.shared.not_empty.notify_one();
+
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving end of a channel.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Take a value from the locked state, waking a blocked sender.
+    fn take(&self, state: &mut State<T>) -> Option<T> {
+        let value = state.queue.pop_front().ok()?;
+        self.shared.not_full.notify_one();
+
+        Some(value)
+    }
+
+    /// Receive a value, blocking until one is sent. Fails once the channel
+    /// is empty and every sender has been dropped.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(value) = self.take(&mut state) {
+                return Ok(value);
+            }
+
+            if state.senders == 0 {
+                return Err(RecvError::Disconnected);
+            }
+
+            state = self.shared.not_empty.wait(state).unwrap();
+        }
+    }
+
+    /// Receive a value without blocking.
+    pub fn try_recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+
+        match self.take(&mut state) {
+            Some(value) => Ok(value),
+            None if state.senders == 0 => Err(RecvError::Disconnected),
+            None => Err(RecvError::Empty),
+        }
+    }
+
+    /// Receive a value, blocking for at most `timeout`. A timeout too long
+    /// to represent as a deadline blocks like `recv`.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvError> {
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            return self.recv();
+        };
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(value) = self.take(&mut state) {
+                return Ok(value);
+            }
+
+            if state.senders == 0 {
+                return Err(RecvError::Disconnected);
+            }
+
+            let now = Instant::now();
+
+            if now >= deadline {
+                return Err(RecvError::Timeout);
+            }
+
+            state = self
+                .shared
+                .not_empty
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Returns an iterator that blocks waiting for values and ends once the
+    /// channel is disconnected.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self)
+    }
+
+    /// Returns an iterator over the values already queued, without blocking.
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter(self)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver = false;
+        self.shared.not_full.notify_all();
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+/// A blocking iterator over the values received on a channel.
+pub struct Iter<'a, T>(&'a Receiver<T>);
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.recv().ok()
+    }
+}
+
+/// A non-blocking iterator over the values queued on a channel.
+pub struct TryIter<'a, T>(&'a Receiver<T>);
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.try_recv().ok()
+    }
+}
+
+/// An owning blocking iterator over the values received on a channel.
+pub struct IntoIter<T>(Receiver<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
@@ -0,0 +1,358 @@
+use crate::doublylinkedlist::{self, LinkedList, NodeHandle};
+use std::iter::{FromIterator, IntoIterator};
+
+/// An iterator over the elements of a circular linked list, going around the
+/// ring a given number of times.
+///
+/// The ring cannot be modified while the iterator is alive:
+///
+/// ```compile_fail
+/// use linkedlist::circularlist::CircularList;
+///
+/// let mut cl = CircularList::from([1, 2, 3]);
+/// let iter = cl.iter();
+/// cl.pop_front();
+/// drop(iter);
+/// ```
+pub struct Iter<'a, T> {
+    /// The linked list holding the ring.
+    list: &'a LinkedList<T>,
+    /// The iterator over the current lap.
+    lap: doublylinkedlist::Iter<'a, T>,
+    /// The number of elements left to yield.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let value = match self.lap.next() {
+            Some(value) => value,
+            None => {
+                self.lap = self.list.iter();
+                self.lap.next()?
+            }
+        };
+        self.remaining -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator that moves the elements out of a circular linked list.
+pub struct IntoIter<T>(doublylinkedlist::IntoIter<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// A circular doubly linked list. The last node links back to the first, and
+/// a `current` pointer can be moved around the ring.
+///
+/// The ring is stored in a doubly linked list, whose end wraps around to its
+/// start when the cursor moves past it. The cursor is a handle to its node, so
+/// it stays on the same value while the ring is rotated.
+pub struct CircularList<T> {
+    /// The nodes of the ring, from its start to its end.
+    list: LinkedList<T>,
+    /// A handle to the node the cursor points to.
+    current: Option<NodeHandle<T>>,
+}
+
+impl<T> CircularList<T> {
+    /// Create an empty circular linked list.
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            current: None,
+        }
+    }
+
+    /// Get the number of nodes in the ring.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Check if the ring contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Get a reference to the value at the start of the ring.
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Get a reference to the value at the end of the ring, just before the start.
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Get a reference to the value at the cursor.
+    pub fn current(&self) -> Option<&T> {
+        let current = self.current.as_ref()?;
+
+        self.list.get_handle(current).ok()
+    }
+
+    /// Get a mutable reference to the value at the cursor.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let current = self.current.as_ref()?;
+
+        self.list.get_handle_mut(current).ok()
+    }
+
+    /// Get a handle to the node after the one a handle refers to, wrapping
+    /// from the end of the ring to its start.
+    fn wrapping_next(&self, handle: &NodeHandle<T>) -> Option<NodeHandle<T>> {
+        match self.list.next_handle(handle) {
+            Ok(Some(next)) => Some(next),
+            _ => self.list.front_handle(),
+        }
+    }
+
+    /// Get a handle to the node before the one a handle refers to, wrapping
+    /// from the start of the ring to its end.
+    fn wrapping_prev(&self, handle: &NodeHandle<T>) -> Option<NodeHandle<T>> {
+        match self.list.prev_handle(handle) {
+            Ok(Some(prev)) => Some(prev),
+            _ => self.list.back_handle(),
+        }
+    }
+
+    /// Move the cursor to the next node, wrapping around the ring.
+    pub fn advance(&mut self) {
+        if let Some(current) = self.current.take() {
+            self.current = self.wrapping_next(&current);
+        }
+    }
+
+    /// Move the cursor to the previous node, wrapping around the ring.
+    pub fn retreat(&mut self) {
+        if let Some(current) = self.current.take() {
+            self.current = self.wrapping_prev(&current);
+        }
+    }
+
+    /// Move the cursor back to the start of the ring.
+    pub fn reset_current(&mut self) {
+        self.current = self.list.front_handle();
+    }
+
+    /// Insert a value at the start of the ring. The cursor is placed on the
+    /// value if the ring was empty.
+    pub fn push_front(&mut self, value: T) {
+        let handle = self.list.push_front_handle(value);
+        self.current.get_or_insert(handle);
+    }
+
+    /// Insert a value at the end of the ring, just before the start. The
+    /// cursor is placed on the value if the ring was empty.
+    pub fn push_back(&mut self, value: T) {
+        let handle = self.list.push_back_handle(value);
+        self.current.get_or_insert(handle);
+    }
+
+    /// Move the cursor to the start of the ring if its node was removed. The
+    /// removed node was either the start or the end of the ring, and the node
+    /// after both of them is the new start.
+    fn repair_current(&mut self) {
+        let removed = self
+            .current
+            .as_ref()
+            .is_some_and(|current| self.list.get_handle(current).is_err());
+
+        if removed {
+            self.current = self.list.front_handle();
+        }
+    }
+
+    /// Remove the value at the start of the ring. The cursor moves to the next
+    /// node if it pointed to the removed one.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.list.pop_front().ok()?;
+        self.repair_current();
+
+        Some(value)
+    }
+
+    /// Remove the value at the end of the ring. The cursor moves to the start
+    /// of the ring if it pointed to the removed node.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let value = self.list.pop_back().ok()?;
+        self.repair_current();
+
+        Some(value)
+    }
+
+    /// Remove the value at the cursor, moving the cursor to the next node and
+    /// keeping the ring closed.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let next = self.wrapping_next(&current);
+        let value = self.list.remove_handle(&current).ok()?;
+
+        if !self.list.is_empty() {
+            self.current = next;
+        }
+
+        Some(value)
+    }
+
+    /// Rotate the ring so that the value at index `n` becomes the start. This
+    /// walks `min(n, len - n)` nodes.
+    pub fn rotate_left(&mut self, n: usize) {
+        if !self.list.is_empty() {
+            self.list.rotate_left(n % self.list.len());
+        }
+    }
+
+    /// Rotate the ring so that the value `n` places before the start becomes
+    /// the start. This walks `min(n, len - n)` nodes.
+    pub fn rotate_right(&mut self, n: usize) {
+        if !self.list.is_empty() {
+            self.list.rotate_right(n % self.list.len());
+        }
+    }
+
+    /// Clear the ring.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.current = None;
+    }
+
+    /// Returns an iterator over the elements, going around the ring once.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.iter_cycle(1)
+    }
+
+    /// Returns an iterator over the elements, going around the ring `laps`
+    /// times. The number of elements yielded saturates at `usize::MAX`.
+    pub fn iter_cycle(&self, laps: usize) -> Iter<'_, T> {
+        Iter {
+            list: &self.list,
+            lap: self.list.iter(),
+            remaining: self.list.len().saturating_mul(laps),
+        }
+    }
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for CircularList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for CircularList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.list.fmt(f)
+    }
+}
+
+impl<T> From<Vec<T>> for CircularList<T> {
+    fn from(vector: Vec<T>) -> Self {
+        LinkedList::from(vector).into()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for CircularList<T> {
+    fn from(arr: [T; N]) -> Self {
+        LinkedList::from(arr).into()
+    }
+}
+
+impl<T> From<LinkedList<T>> for CircularList<T> {
+    fn from(ll: LinkedList<T>) -> Self {
+        Self {
+            current: ll.front_handle(),
+            list: ll,
+        }
+    }
+}
+
+impl<T> From<CircularList<T>> for LinkedList<T> {
+    fn from(cl: CircularList<T>) -> Self {
+        cl.list
+    }
+}
+
+impl<T> From<CircularList<T>> for Vec<T> {
+    fn from(cl: CircularList<T>) -> Self {
+        cl.list.into()
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        iter.into_iter().collect::<LinkedList<T>>().into()
+    }
+}
+
+impl<T> IntoIterator for CircularList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.list.into_iter())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CircularList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for CircularList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.list == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for CircularList<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.list == *other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for CircularList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.list == *other
+    }
+}
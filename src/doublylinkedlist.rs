@@ -1,478 +1,1009 @@
 #![allow(dead_code)]
 
-use std::cell::RefCell;
+use crate::arena::{self, Arena, Slot, Storage};
+use crate::linkedlist::{self, index_out_of_bounds, PARALLEL_SORT_THRESHOLD};
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec;
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::VecDeque;
 use std::iter::{FromIterator, IntoIterator};
-use std::rc::Rc;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Linked list errors.
 pub enum LinkedListError {
     IndexOutOfBounds,
     InvalidArraySize,
+    StaleHandle,
+    ForeignHandle,
+    CapacityExceeded,
 }
 
-impl std::fmt::Debug for LinkedListError {
+impl std::fmt::Display for LinkedListError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
             Self::IndexOutOfBounds => write!(f, "linked list index out of bounds"),
             Self::InvalidArraySize => write!(f, "invalid array size"),
+            Self::StaleHandle => write!(f, "node handle refers to a removed node"),
+            Self::ForeignHandle => write!(f, "node handle belongs to a different linked list"),
+            Self::CapacityExceeded => write!(f, "linked list capacity exceeded"),
         }
     }
 }
 
+impl std::fmt::Debug for LinkedListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for LinkedListError {}
+
 /// Linked list result type.
 pub type Result<T> = core::result::Result<T, LinkedListError>;
 
-/// A node in a linked list.
-#[derive(Clone, Debug)]
-struct Node<T> {
+/// The result of an operation that hands its value back on failure.
+pub type TryResult<T> = core::result::Result<(), (LinkedListError, T)>;
+
+/// The identity to give the next linked list created.
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Get a new, unique linked list identity.
+fn next_list_id() -> usize {
+    NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// The value stored in a node of a linked list.
+#[derive(Clone)]
+struct Entry<T> {
     /// The node's value.
     value: T,
-    /// The next node in the linked list.
-    next: Option<Rc<RefCell<Node<T>>>>,
-    /// The previous node in the linked list.
-    prev: Option<Rc<RefCell<Node<T>>>>,
-}
-
-impl<T> Node<T> {
-    // /// Get a reference to the value at a node by index.
-    // pub fn get_value(&self, index: usize) -> Result<Ref<T>> {
-    //     match &self.next {
-    //         Some(next) => {
-    //             let next_ref = next.borrow();
-
-    //             if index == 1 {
-    //                 Ok(Ref::map(next_ref, |node| &node.value))
-    //             } else {
-    //                 next_ref.get_value(index - 1)
-    //             }
-    //         }
-    //         None => Err(LinkedListError::IndexOutOfBounds),
-    //     }
-    // }
-
-    // /// Get a mutable reference to the value at a node by index.
-    // pub fn get_value_mut(&mut self, index: usize) -> Result<RefMut<T>> {
-    //     match &self.next {
-    //         Some(next) => {
-    //             let mut next_ref = next.borrow_mut();
-
-    //             if index == 1 {
-    //                 Ok(RefMut::map(next_ref, |node| &mut node.value))
-    //             } else {
-    //                 next_ref.get_value_mut(index - 1)
-    //             }
-    //         }
-    //         None => Err(LinkedListError::IndexOutOfBounds),
-    //     }
-    // }
+    /// The generation the node was stored in, which handles to it must match.
+    generation: usize,
+}
 
-    /// Set the value at a node by index.
-    pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
-        if index == 0 {
-            self.value = value;
-            Ok(())
-        } else {
-            match &self.next {
-                Some(next) => next.borrow_mut().set_value(index - 1, value),
-                None => Err(LinkedListError::IndexOutOfBounds),
-            }
-        }
+/// The slots holding the nodes of a linked list.
+type Slots<T, A> = vec::Vec<Slot<Entry<T>>, A>;
+
+/// An iterator over the elements of a linked list.
+pub struct Iter<'a, T, A: Allocator + Clone = Global>(arena::Iter<'a, Slots<T, A>>);
+
+impl<'a, T, A: Allocator + Clone> Iterator for Iter<'a, T, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|entry| &entry.value)
     }
 
-    /// Insert a value in a new node at a given index.
-    pub fn push(&mut self, index: usize, value: T) -> Result<()> {
-        if index == 1 {
-            if self.next.is_some() {
-                let next = self.next.take().unwrap();
-                let mut orig_next = next.borrow_mut();
-                let new_node = Rc::new(RefCell::new(Node {
-                    value,
-                    next: Some(Rc::clone(&next)),
-                    prev: Some(Rc::clone(&orig_next.prev.take().unwrap())),
-                }));
-                orig_next.prev = Some(Rc::clone(&new_node));
-                self.next = Some(new_node);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
 
-                Ok(())
-            } else {
-                Err(LinkedListError::IndexOutOfBounds)
-            }
-        } else {
-            match &self.next {
-                Some(next) => next.borrow_mut().push(index - 1, value),
-                None => Err(LinkedListError::IndexOutOfBounds),
-            }
-        }
+impl<T, A: Allocator + Clone> DoubleEndedIterator for Iter<'_, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|entry| &entry.value)
     }
+}
 
-    /// Remove the node at a given index, returning the node's owned value.
-    pub fn pop(&mut self, index: usize) -> Result<T> {
-        if index == 1 {
-            if self.next.is_some() {
-                let pop_node_ref = self.next.take().unwrap();
-                {
-                    let mut pop_node_borrow = pop_node_ref.borrow_mut();
-                    let new_next_ref = pop_node_borrow.next.take().unwrap();
-                    {
-                        let mut new_next = new_next_ref.borrow_mut();
-                        new_next.prev = pop_node_borrow.prev.take();
-                    }
-                    self.next = Some(new_next_ref);
-                }
-                let pop_node = Rc::try_unwrap(pop_node_ref)
-                    .map_err(|_| "attempted to unwrap Rc with multiple references".to_owned())
-                    .unwrap();
-                let value = pop_node.into_inner().value;
-
-                Ok(value)
-            } else {
-                Err(LinkedListError::IndexOutOfBounds)
-            }
-        } else {
-            match &self.next {
-                Some(next) => next.borrow_mut().pop(index - 1),
-                None => Err(LinkedListError::IndexOutOfBounds),
-            }
-        }
+impl<T, A: Allocator + Clone> ExactSizeIterator for Iter<'_, T, A> {}
+
+/// An iterator over the elements of a linked list, allowing for them to be
+/// mutated.
+pub struct IterMut<'a, T>(arena::IterMut<'a, Entry<T>>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|entry| &mut entry.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
     }
 }
 
-impl<T: Copy> Node<T> {
-    /// Gets a copy of the value at a given index.
-    pub fn get(&self, index: usize) -> Result<T> {
-        if index == 0 {
-            Ok(self.value)
-        } else {
-            match &self.next {
-                Some(next) => next.borrow().get(index - 1),
-                None => Err(LinkedListError::IndexOutOfBounds),
-            }
-        }
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|entry| &mut entry.value)
     }
 }
 
-impl<T: PartialEq> PartialEq for Node<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.value != other.value {
-            false
-        } else if self.next.is_some() && other.next.is_some() {
-            *self.next.as_ref().unwrap().borrow() == *other.next.as_ref().unwrap().borrow()
-        } else if self.next.is_none() && other.next.is_none() {
-            true
-        } else {
-            false
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// A handle to a node in a linked list, allowing the node to be accessed
+/// without walking the list. A handle is invalidated when its node is removed
+/// or moved to another linked list.
+pub struct NodeHandle<T> {
+    /// The identity of the linked list the node belongs to.
+    list: usize,
+    /// The slot holding the node.
+    slot: usize,
+    /// The generation of the node in the slot.
+    generation: usize,
+    /// The type of the node's value.
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T> std::fmt::Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("NodeHandle")
+    }
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            slot: self.slot,
+            generation: self.generation,
+            value: PhantomData,
         }
     }
 }
 
-/// A linked list.
-#[derive(Clone, Debug)]
-pub struct LinkedList<T> {
-    /// The first node in the linked list.
-    head: Option<Rc<RefCell<Node<T>>>>,
-    /// The last node in the linked list.
-    tail: Option<Rc<RefCell<Node<T>>>>,
-    /// The total number of nodes in the linked list.
-    size: usize,
+/// A linked list whose nodes are stored in slots allocated with `A`. Removed
+/// nodes return their slot for reuse by later insertions.
+///
+/// Nodes keep their slot for as long as they stay in the linked list, so
+/// handles to them stay valid through relinking operations such as sorting
+/// and rotation. Moving values into another linked list, as `append` does,
+/// stores them in new slots there, and `iter_mut` moves nodes into list order
+/// in their slots; both invalidate handles to the moved nodes.
+///
+/// A linked list compares equal to linked lists, slices, arrays, `Vec`s and
+/// `VecDeque`s of any comparable element type, on either side of `==`.
+pub struct LinkedList<T, A: Allocator + Clone = Global> {
+    /// The nodes, linked in slots.
+    arena: Arena<Slots<T, A>>,
+    /// The identity of the linked list, which handles to its nodes must match.
+    id: usize,
+    /// The generation to give the next node stored.
+    generation: usize,
 }
 
 impl<T> LinkedList<T> {
     /// Create an empty linked list.
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Create an empty linked list whose nodes are allocated with `alloc`.
+    pub fn new_in(alloc: A) -> Self {
         Self {
-            head: None,
-            tail: None,
-            size: 0,
+            arena: Arena::new(vec::Vec::new_in(alloc)),
+            id: next_list_id(),
+            generation: 0,
         }
     }
 
+    /// Get a reference to the allocator used for the nodes.
+    pub fn allocator(&self) -> &A {
+        self.arena.storage().allocator()
+    }
+
     /// Get the number of nodes in the linked list.
     pub fn len(&self) -> usize {
-        self.size
-    }
-
-    // /// Get a reference to the value at a node by index.
-    // pub fn get_value(&self, index: usize) -> Result<Ref<T>> {
-    //     match &self.head {
-    //         Some(head) => {
-    //             let head_ref = head.borrow();
-
-    //             if index == 1 {
-    //                 Ok(Ref::map(head_ref, |node| &node.value))
-    //             } else {
-    //                 head_ref.get_value(index)
-    //             }
-    //         }
-    //         None => Err(LinkedListError::IndexOutOfBounds),
-    //     }
-    // }
-
-    // /// Get a mutable reference to the value at a node by index.
-    // pub fn get_value_mut(&mut self, index: usize) -> Result<RefMut<T>> {
-    //     match &self.head {
-    //         Some(head) => {
-    //             let mut head_ref = head.borrow_mut();
-
-    //             if index == 1 {
-    //                 Ok(RefMut::map(head_ref, |node| &mut node.value))
-    //             } else {
-    //                 head_ref.get_value_mut(index)
-    //             }
-    //         }
-    //         None => Err(LinkedListError::IndexOutOfBounds),
-    //     }
-    // }
+        self.arena.len()
+    }
+
+    /// Check if the linked list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.arena.len() == 0
+    }
+
+    /// Get a reference to the value at the start of the linked list.
+    pub fn front(&self) -> Option<&T> {
+        self.arena.head().map(|head| self.value(head))
+    }
+
+    /// Get a reference to the value at the end of the linked list.
+    pub fn back(&self) -> Option<&T> {
+        self.arena.tail().map(|tail| self.value(tail))
+    }
+
+    /// Get a mutable reference to the value at the start of the linked list.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let head = self.arena.head()?;
+
+        Some(self.value_mut(head))
+    }
+
+    /// Get a mutable reference to the value at the end of the linked list.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let tail = self.arena.tail()?;
+
+        Some(self.value_mut(tail))
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let slot = self.arena.slot_at(index)?;
+
+        Some(self.value(slot))
+    }
+
+    /// Get a mutable reference to the value at a given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let slot = self.arena.slot_at(index)?;
+
+        Some(self.value_mut(slot))
+    }
+
+    /// Get a reference to the first value. This is the same as `front`.
+    pub fn first(&self) -> Option<&T> {
+        self.front()
+    }
+
+    /// Get a reference to the last value. This is the same as `back`.
+    pub fn last(&self) -> Option<&T> {
+        self.back()
+    }
+
+    /// Get a reference to the value at a node by index.
+    pub fn get_value(&self, index: usize) -> Result<&T> {
+        self.get(index).ok_or(LinkedListError::IndexOutOfBounds)
+    }
+
+    /// Get a mutable reference to the value at a node by index.
+    pub fn get_value_mut(&mut self, index: usize) -> Result<&mut T> {
+        self.get_mut(index).ok_or(LinkedListError::IndexOutOfBounds)
+    }
 
     /// Set the value at a node by index.
     pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
-        match &self.head {
-            Some(head) => head.borrow_mut().set_value(index, value),
-            None => Err(LinkedListError::IndexOutOfBounds),
-        }
+        self.try_set_value(index, value).map_err(|(error, _)| error)
     }
 
-    /// Insert a value in a new node at a given index in the linked list.
-    pub fn push(&mut self, index: usize, value: T) -> Result<()> {
-        if self.size == 0 {
-            if index == 0 {
-                let new_node = Rc::new(RefCell::new(Node {
-                    value,
-                    next: None,
-                    prev: None,
-                }));
-                self.head = Some(Rc::clone(&new_node));
-                self.tail = Some(new_node);
-                self.size += 1;
+    /// Set the value at a node by index, handing the value back on failure.
+    pub fn try_set_value(&mut self, index: usize, value: T) -> TryResult<T> {
+        match self.arena.slot_at(index) {
+            Some(slot) => {
+                *self.value_mut(slot) = value;
 
                 Ok(())
-            } else {
-                Err(LinkedListError::IndexOutOfBounds)
             }
-        } else if index == 0 {
-            self.push_front(value);
+            None => Err((LinkedListError::IndexOutOfBounds, value)),
+        }
+    }
+
+    /// Insert a value in a new node at a given index in the linked list.
+    pub fn push(&mut self, index: usize, value: T) -> Result<()> {
+        self.try_push(index, value).map_err(|(error, _)| error)
+    }
 
-            Ok(())
-        } else if index == self.size {
+    /// Insert a value in a new node at a given index in the linked list,
+    /// handing the value back on failure.
+    pub fn try_push(&mut self, index: usize, value: T) -> TryResult<T> {
+        if index == self.len() {
             self.push_back(value);
 
-            Ok(())
-        } else {
-            match self.head.as_ref().unwrap().borrow_mut().push(index, value) {
-                Ok(x) => {
-                    self.size += 1;
-                    Ok(x)
-                }
-                Err(e) => Err(e),
+            return Ok(());
+        }
+
+        match self.arena.slot_at(index) {
+            Some(next) => {
+                let prev = self.arena.node(next).prev;
+                self.link(value, prev, Some(next));
+
+                Ok(())
             }
+            None => Err((LinkedListError::IndexOutOfBounds, value)),
         }
     }
 
     /// Insert a value at the start of the linked list.
     pub fn push_front(&mut self, value: T) {
-        if self.size == 0 {
-            let new_node = Rc::new(RefCell::new(Node {
-                value,
-                next: None,
-                prev: None,
-            }));
-            self.head = Some(Rc::clone(&new_node));
-            self.tail = Some(new_node);
-        } else {
-            let orig_head = self.head.take().unwrap();
-            let new_node = Rc::new(RefCell::new(Node {
-                value,
-                next: Some(Rc::clone(&orig_head)),
-                prev: None,
-            }));
-            orig_head.borrow_mut().prev = Some(Rc::clone(&new_node));
-            self.head = Some(new_node);
-        }
-
-        self.size += 1;
+        self.link(value, None, self.arena.head());
     }
 
     /// Insert a value at the end of the linked list.
     pub fn push_back(&mut self, value: T) {
-        if self.size == 0 {
-            let new_node = Rc::new(RefCell::new(Node {
-                value,
-                next: None,
-                prev: None,
-            }));
-            self.head = Some(Rc::clone(&new_node));
-            self.tail = Some(new_node);
-        } else {
-            let orig_tail = self.tail.take().unwrap();
-            let new_node = Rc::new(RefCell::new(Node {
-                value,
-                next: None,
-                prev: Some(Rc::clone(&orig_tail)),
-            }));
-            orig_tail.borrow_mut().next = Some(Rc::clone(&new_node));
-            self.tail = Some(new_node);
-        }
-
-        self.size += 1;
+        self.link(value, self.arena.tail(), None);
     }
 
     /// Remove the node at a given index, returning the node's owned value.
     pub fn pop(&mut self, index: usize) -> Result<T> {
-        if index == 0 {
-            self.pop_front()
-        } else if index == self.size - 1 {
-            self.pop_back()
-        } else {
-            match &self.head {
-                Some(head) => match head.borrow_mut().pop(index) {
-                    Ok(x) => {
-                        self.size -= 1;
-                        Ok(x)
-                    }
-                    Err(e) => Err(e),
-                },
-                None => Err(LinkedListError::IndexOutOfBounds),
-            }
-        }
+        let slot = self
+            .arena
+            .slot_at(index)
+            .ok_or(LinkedListError::IndexOutOfBounds)?;
+
+        Ok(self.unlink(slot))
     }
 
     /// Remove the first node, returning the node's owned value.
     pub fn pop_front(&mut self) -> Result<T> {
-        if self.size == 1 {
-            self.tail = None;
-            let pop_node_ref = self.head.take().unwrap();
-            let pop_node = Rc::try_unwrap(pop_node_ref)
-                .map_err(|_| "attempted to unwrap Rc with multiple references".to_owned())
-                .unwrap();
-            let value = pop_node.into_inner().value;
-            self.size -= 1;
-
-            Ok(value)
-        } else if self.size > 1 {
-            let pop_node_ref = self.head.take().unwrap();
-            let new_head = pop_node_ref.borrow_mut().next.take().unwrap();
-            new_head.borrow_mut().prev = None;
-            self.head = Some(new_head);
-            let pop_node = Rc::try_unwrap(pop_node_ref)
-                .map_err(|_| "attempted to unwrap Rc with multiple references".to_owned())
-                .unwrap();
-            let value = pop_node.into_inner().value;
-            self.size -= 1;
-
-            Ok(value)
-        } else {
-            Err(LinkedListError::IndexOutOfBounds)
-        }
+        let head = self.arena.head().ok_or(LinkedListError::IndexOutOfBounds)?;
+
+        Ok(self.unlink(head))
     }
 
     /// Remove the last node, returning the node's owned value.
     pub fn pop_back(&mut self) -> Result<T> {
-        if self.size == 1 {
-            self.pop_front()
-        } else if self.size > 1 {
-            let pop_node_ref = self.tail.take().unwrap();
-            let new_tail = pop_node_ref.borrow_mut().prev.take().unwrap();
-            new_tail.borrow_mut().next = None;
-            self.tail = Some(new_tail);
-            let pop_node = Rc::try_unwrap(pop_node_ref)
-                .map_err(|_| "attempted to unwrap Rc with multiple references".to_owned())
-                .unwrap();
-            let value = pop_node.into_inner().value;
-            self.size -= 1;
-
-            Ok(value)
+        let tail = self.arena.tail().ok_or(LinkedListError::IndexOutOfBounds)?;
+
+        Ok(self.unlink(tail))
+    }
+
+    /// Clear the linked list, keeping its slots allocated for reuse.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+    }
+
+    /// Shorten the linked list to `len` nodes, dropping the rest. Does
+    /// nothing if the linked list is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop_back().unwrap();
+        }
+    }
+
+    /// Resize the linked list to `len` nodes, filling new nodes at the end
+    /// with values returned by `f`.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, len: usize, mut f: F) {
+        self.truncate(len);
+
+        while self.len() < len {
+            self.push_back(f());
+        }
+    }
+
+    /// Unlink consecutive duplicates in a single pass, passing each removed
+    /// value to `removed`.
+    fn dedup_by_with<F, R>(&mut self, mut same_bucket: F, mut removed: R)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+        R: FnMut(T),
+    {
+        let Some(mut current) = self.arena.head() else {
+            return;
+        };
+
+        while let Some(next) = self.arena.node(current).next {
+            let (value, previous) = self.arena.values_mut(next, current);
+
+            if same_bucket(&mut value.value, &mut previous.value) {
+                removed(self.unlink(next));
+            } else {
+                current = next;
+            }
+        }
+    }
+
+    /// Remove consecutive values for which `same_bucket(value, previous)`
+    /// returns true, keeping the first value of each run. Duplicate nodes are
+    /// unlinked in a single pass.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+        self.dedup_by_with(same_bucket, drop);
+    }
+
+    /// Remove consecutive values that map to the same key, keeping the first
+    /// value of each run.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Remove consecutive values for which `same_bucket(value, previous)`
+    /// returns true like `dedup_by`, returning the removed values in order.
+    pub fn dedup_by_drain<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.dedup_by_with(same_bucket, |value| removed.push(value));
+
+        removed
+    }
+
+    /// Swap the values at two indices.
+    ///
+    /// Panics if either index is out of bounds.
+    #[track_caller]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let a = self
+            .arena
+            .slot_at(i)
+            .unwrap_or_else(|| index_out_of_bounds(i, self.len()));
+        let b = self
+            .arena
+            .slot_at(j)
+            .unwrap_or_else(|| index_out_of_bounds(j, self.len()));
+
+        if a != b {
+            let (a, b) = self.arena.values_mut(a, b);
+            std::mem::swap(&mut a.value, &mut b.value);
+        }
+    }
+
+    /// Rotate the linked list `n` places to the left, so the value at index
+    /// `n` becomes the first. Nodes are relinked, so handles stay valid.
+    ///
+    /// Panics if `n` is greater than the length of the linked list.
+    pub fn rotate_left(&mut self, n: usize) {
+        let len = self.len();
+        assert!(n <= len, "rotation amount {} exceeds the len {}", n, len);
+
+        if n <= len / 2 {
+            for _ in 0..n {
+                let head = self.arena.head().unwrap();
+                self.arena.detach(head);
+                self.arena.attach(head, self.arena.tail(), None);
+            }
         } else {
-            Err(LinkedListError::IndexOutOfBounds)
+            for _ in n..len {
+                let tail = self.arena.tail().unwrap();
+                self.arena.detach(tail);
+                self.arena.attach(tail, None, self.arena.head());
+            }
         }
     }
 
-    /// Clear the linked list.
-    pub fn clear(&mut self) {
-        self.head = None;
-        self.tail = None;
-        self.size = 0;
+    /// Rotate the linked list `n` places to the right, so the value at index
+    /// `len - n` becomes the first. Nodes are relinked, so handles stay
+    /// valid.
+    ///
+    /// Panics if `n` is greater than the length of the linked list.
+    pub fn rotate_right(&mut self, n: usize) {
+        let len = self.len();
+        assert!(n <= len, "rotation amount {} exceeds the len {}", n, len);
+
+        self.rotate_left(len - n);
     }
 
     /// Reverse the elements in the linked list in place.
     pub fn reverse(&mut self) {
-        let mut orig = Self {
-            head: self.head.take(),
-            tail: self.tail.take(),
-            size: self.size,
+        self.arena.reverse();
+    }
+
+    /// Returns an iterator over the elements in the linked list.
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter(self.arena.iter())
+    }
+
+    /// Returns an iterator over the elements in the linked list, allowing for
+    /// them to be mutated. The nodes are first moved into list order in their
+    /// slots, so that the iterator can walk the slots directly. Moving the
+    /// nodes invalidates handles to them.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut(self.arena.iter_mut())
+    }
+
+    /// Get the index of the first value for which `f` returns `true`.
+    pub(crate) fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().position(f)
+    }
+
+    /// Insert a value before the first value for which `f(other, &value)`
+    /// returns `true`, or at the end if there is none, returning the index at
+    /// which it was inserted. The linked list is walked once.
+    pub(crate) fn insert_before_first<F: FnMut(&T, &T) -> bool>(
+        &mut self,
+        value: T,
+        mut f: F,
+    ) -> usize {
+        let mut index = 0;
+        let mut next = self.arena.head();
+
+        while let Some(slot) = next {
+            let node = self.arena.node(slot);
+
+            if f(&node.value.value, &value) {
+                break;
+            }
+
+            next = node.next;
+            index += 1;
+        }
+
+        let prev = match next {
+            Some(slot) => self.arena.node(slot).prev,
+            None => self.arena.tail(),
+        };
+        self.link(value, prev, next);
+
+        index
+    }
+
+    /// Remove the first value for which `f` returns `Ordering::Equal`,
+    /// stopping at the first value for which it returns `Ordering::Greater`.
+    /// The linked list is walked once.
+    pub(crate) fn remove_first_by<F: FnMut(&T) -> CmpOrdering>(&mut self, mut f: F) -> Option<T> {
+        let mut next = self.arena.head();
+
+        while let Some(slot) = next {
+            let node = self.arena.node(slot);
+
+            match f(&node.value.value) {
+                CmpOrdering::Less => next = node.next,
+                CmpOrdering::Equal => return Some(self.unlink(slot)),
+                CmpOrdering::Greater => break,
+            }
+        }
+
+        None
+    }
+
+    /// Move all values from `other` to the end of the linked list, leaving
+    /// `other` empty. Each value is stored in a new slot of this linked list
+    /// and `other`'s slots are kept for reuse, so handles to `other`'s nodes
+    /// are invalidated while handles to this linked list's nodes stay valid.
+    pub fn append(&mut self, other: &mut Self) {
+        while let Ok(value) = other.pop_front() {
+            self.push_back(value);
+        }
+    }
+
+    /// Get a reference to the value in a used slot.
+    fn value(&self, slot: usize) -> &T {
+        &self.arena.node(slot).value.value
+    }
+
+    /// Get a mutable reference to the value in a used slot.
+    fn value_mut(&mut self, slot: usize) -> &mut T {
+        &mut self.arena.node_mut(slot).value.value
+    }
+
+    /// Store a value in a new node, reusing an unused slot if there is one,
+    /// and link it between two nodes.
+    fn link(&mut self, value: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        let entry = Entry {
+            value,
+            generation: self.generation,
         };
+        self.generation += 1;
 
-        self.size = 0;
+        self.arena.link(entry, prev, next)
+    }
 
-        while orig.len() > 0 {
-            let value = orig.pop_front().unwrap();
-            self.push_front(value);
+    /// Unlink the node in a slot and free the slot, returning the node's owned
+    /// value.
+    fn unlink(&mut self, slot: usize) -> T {
+        self.arena.unlink(slot).value
+    }
+
+    /// Get a handle to the node in a used slot.
+    fn handle(&self, slot: usize) -> NodeHandle<T> {
+        NodeHandle {
+            list: self.id,
+            slot,
+            generation: self.arena.node(slot).value.generation,
+            value: PhantomData,
         }
     }
+
+    /// Get the slot a handle refers to, checking that its node is still in
+    /// this linked list.
+    fn handle_slot(&self, handle: &NodeHandle<T>) -> Result<usize> {
+        if handle.list != self.id {
+            return Err(LinkedListError::ForeignHandle);
+        }
+
+        match self.arena.get(handle.slot) {
+            Some(node) if node.value.generation == handle.generation => Ok(handle.slot),
+            _ => Err(LinkedListError::StaleHandle),
+        }
+    }
+
+    /// Get a reference to the value at the node a handle refers to.
+    pub(crate) fn handle_value(&self, handle: &NodeHandle<T>) -> Result<&T> {
+        let slot = self.handle_slot(handle)?;
+
+        Ok(self.value(slot))
+    }
+
+    /// Get a mutable reference to the value at the node a handle refers to.
+    pub(crate) fn handle_value_mut(&mut self, handle: &NodeHandle<T>) -> Result<&mut T> {
+        let slot = self.handle_slot(handle)?;
+
+        Ok(self.value_mut(slot))
+    }
+
+    /// Get a reference to the value at the node a handle refers to.
+    pub fn get_handle(&self, handle: &NodeHandle<T>) -> Result<&T> {
+        self.handle_value(handle)
+    }
+
+    /// Get a mutable reference to the value at the node a handle refers to.
+    pub fn get_handle_mut(&mut self, handle: &NodeHandle<T>) -> Result<&mut T> {
+        self.handle_value_mut(handle)
+    }
+
+    /// Get a handle to the node after the one a handle refers to.
+    pub(crate) fn next_handle(&self, handle: &NodeHandle<T>) -> Result<Option<NodeHandle<T>>> {
+        let slot = self.handle_slot(handle)?;
+
+        Ok(self.arena.node(slot).next.map(|next| self.handle(next)))
+    }
+
+    /// Get a handle to the node before the one a handle refers to.
+    pub(crate) fn prev_handle(&self, handle: &NodeHandle<T>) -> Result<Option<NodeHandle<T>>> {
+        let slot = self.handle_slot(handle)?;
+
+        Ok(self.arena.node(slot).prev.map(|prev| self.handle(prev)))
+    }
+
+    /// Insert a value at the start of the linked list, returning a handle to
+    /// its node.
+    pub fn push_front_handle(&mut self, value: T) -> NodeHandle<T> {
+        let slot = self.link(value, None, self.arena.head());
+
+        self.handle(slot)
+    }
+
+    /// Insert a value at the end of the linked list, returning a handle to its
+    /// node.
+    pub fn push_back_handle(&mut self, value: T) -> NodeHandle<T> {
+        let slot = self.link(value, self.arena.tail(), None);
+
+        self.handle(slot)
+    }
+
+    /// Get a handle to the first node.
+    pub fn front_handle(&self) -> Option<NodeHandle<T>> {
+        self.arena.head().map(|head| self.handle(head))
+    }
+
+    /// Get a handle to the last node.
+    pub fn back_handle(&self) -> Option<NodeHandle<T>> {
+        self.arena.tail().map(|tail| self.handle(tail))
+    }
+
+    /// Insert a value after the node a handle refers to, returning a handle to
+    /// the new node.
+    pub fn insert_after_handle(
+        &mut self,
+        handle: &NodeHandle<T>,
+        value: T,
+    ) -> Result<NodeHandle<T>> {
+        let prev = self.handle_slot(handle)?;
+        let next = self.arena.node(prev).next;
+        let slot = self.link(value, Some(prev), next);
+
+        Ok(self.handle(slot))
+    }
+
+    /// Insert a value before the node a handle refers to, returning a handle
+    /// to the new node.
+    pub fn insert_before_handle(
+        &mut self,
+        handle: &NodeHandle<T>,
+        value: T,
+    ) -> Result<NodeHandle<T>> {
+        let next = self.handle_slot(handle)?;
+        let prev = self.arena.node(next).prev;
+        let slot = self.link(value, prev, Some(next));
+
+        Ok(self.handle(slot))
+    }
+
+    /// Remove the node a handle refers to, returning the node's owned value.
+    pub fn remove_handle(&mut self, handle: &NodeHandle<T>) -> Result<T> {
+        let slot = self.handle_slot(handle)?;
+
+        Ok(self.unlink(slot))
+    }
+
+    /// Move the node a handle refers to to the start of the linked list.
+    pub fn move_handle_to_front(&mut self, handle: &NodeHandle<T>) -> Result<()> {
+        let slot = self.handle_slot(handle)?;
+        self.arena.detach(slot);
+        self.arena.attach(slot, None, self.arena.head());
+
+        Ok(())
+    }
+
+    /// Move the node a handle refers to to the end of the linked list.
+    pub fn move_handle_to_back(&mut self, handle: &NodeHandle<T>) -> Result<()> {
+        let slot = self.handle_slot(handle)?;
+        self.arena.detach(slot);
+        self.arena.attach(slot, self.arena.tail(), None);
+
+        Ok(())
+    }
+
+    /// Merge another linked list into this one. If both lists are sorted
+    /// according to `is_less`, the result is sorted. The merge is stable: on
+    /// ties, values from `self` come first. The nodes already in this linked
+    /// list are relinked, so handles to them stay valid.
+    pub(crate) fn merge_by<F: FnMut(&T, &T) -> bool>(&mut self, mut other: Self, mut is_less: F) {
+        let Some(tail) = self.arena.tail() else {
+            self.append(&mut other);
+            return;
+        };
+
+        self.append(&mut other);
+        let back = self.arena.node_mut(tail).next.take();
+        let mut is_less = |a: &Entry<T>, b: &Entry<T>| is_less(&a.value, &b.value);
+        let head = self
+            .arena
+            .merge_chains(self.arena.head(), back, &mut is_less);
+        self.arena.relink_prev(head);
+    }
+
+    /// Stably sort the linked list with a comparator function, relinking
+    /// nodes rather than allocating. Handles stay valid.
+    pub fn sort_by<F: FnMut(&T, &T) -> CmpOrdering>(&mut self, mut compare: F) {
+        if let Some(head) = self.arena.head() {
+            let mut is_less =
+                |a: &Entry<T>, b: &Entry<T>| compare(&a.value, &b.value) == CmpOrdering::Less;
+            let head = self.arena.sort_chain(head, self.len(), &mut is_less);
+            self.arena.relink_prev(Some(head));
+        }
+    }
+
+    /// Check if both linked lists have the same length and `f` holds for
+    /// every pair of values at the same position.
+    pub(crate) fn eq_by<U, B, F>(&self, other: &LinkedList<U, B>, mut f: F) -> bool
+    where
+        B: Allocator + Clone,
+        F: FnMut(&T, &U) -> bool,
+    {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| f(a, b))
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> LinkedList<T, A> {
+    /// Stably sort the linked list, relinking nodes rather than allocating.
+    /// Handles stay valid.
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
 }
 
-impl<T: Copy> LinkedList<T> {
-    /// Gets a copy of the value at a given index.
-    pub fn get(&self, index: usize) -> Result<T> {
-        match &self.head {
-            Some(head) => head.borrow().get(index),
-            None => Err(LinkedListError::IndexOutOfBounds),
+impl<T: Sync, A: Allocator + Clone> LinkedList<T, A> {
+    /// Stably sort the linked list with a comparator function using several
+    /// threads. The chain of nodes is split in half, the halves are sorted on
+    /// separate threads, and the sorted halves are merged by relinking nodes.
+    /// Threads relink their nodes in a table with one link per slot, so no
+    /// nodes are allocated or moved and handles stay valid.
+    pub fn par_sort_by<F: Fn(&T, &T) -> CmpOrdering + Sync>(&mut self, compare: F) {
+        let Some(head) = self.arena.head() else {
+            return;
+        };
+
+        let slots = self.arena.storage().slots();
+        let links: Vec<AtomicUsize> = slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Used(node) => AtomicUsize::new(node.next.unwrap_or(NO_LINK)),
+                Slot::Free(_) => AtomicUsize::new(NO_LINK),
+            })
+            .collect();
+        let depth = linkedlist::parallel_sort_depth();
+        let head = par_sort_links(slots, &links, head, self.len(), depth, &compare);
+
+        let mut current = head;
+
+        while current != NO_LINK {
+            let next = links[current].load(Ordering::Relaxed);
+            self.arena.node_mut(current).next = (next != NO_LINK).then_some(next);
+            current = next;
+        }
+
+        self.arena.relink_prev(Some(head));
+    }
+
+    /// Stably sort the linked list using several threads, relinking nodes
+    /// rather than allocating. Handles stay valid.
+    pub fn par_sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sort_by(T::cmp);
+    }
+}
+
+/// The link that ends a chain in a parallel sort's table of links.
+const NO_LINK: usize = usize::MAX;
+
+/// Get a reference to the value in a used slot.
+fn slot_value<T>(slots: &[Slot<Entry<T>>], slot: usize) -> &T {
+    match &slots[slot] {
+        Slot::Used(node) => &node.value.value,
+        Slot::Free(_) => unreachable!("linked list refers to an unused slot"),
+    }
+}
+
+/// Merge two sorted chains in a table of links by relinking their nodes,
+/// returning the slot of the merged chain's first node. On ties, nodes from
+/// `a` come first.
+fn merge_links<T, F: Fn(&T, &T) -> CmpOrdering>(
+    slots: &[Slot<Entry<T>>],
+    links: &[AtomicUsize],
+    mut a: usize,
+    mut b: usize,
+    compare: &F,
+) -> usize {
+    let mut head = NO_LINK;
+    let mut tail = NO_LINK;
+
+    while a != NO_LINK && b != NO_LINK {
+        let source = if compare(slot_value(slots, b), slot_value(slots, a)) == CmpOrdering::Less {
+            &mut b
+        } else {
+            &mut a
+        };
+        let slot = *source;
+        *source = links[slot].load(Ordering::Relaxed);
+
+        match tail {
+            NO_LINK => head = slot,
+            tail => links[tail].store(slot, Ordering::Relaxed),
+        }
+
+        tail = slot;
+    }
+
+    let rest = if a == NO_LINK { b } else { a };
+
+    match tail {
+        NO_LINK => rest,
+        tail => {
+            links[tail].store(rest, Ordering::Relaxed);
+
+            head
         }
     }
 }
 
+/// Stably sort a chain of `len` nodes in a table of links by relinking them,
+/// sorting halves on separate threads `depth` levels deep. Returns the slot of
+/// the sorted chain's first node.
+fn par_sort_links<T, F>(
+    slots: &[Slot<Entry<T>>],
+    links: &[AtomicUsize],
+    head: usize,
+    len: usize,
+    depth: u32,
+    compare: &F,
+) -> usize
+where
+    T: Sync,
+    F: Fn(&T, &T) -> CmpOrdering + Sync,
+{
+    if len <= 1 {
+        return head;
+    }
+
+    let mid = len / 2;
+    let mut last = head;
+
+    for _ in 1..mid {
+        last = links[last].load(Ordering::Relaxed);
+    }
+
+    let rest = links[last].swap(NO_LINK, Ordering::Relaxed);
+    let (left, right) = if depth == 0 || len < PARALLEL_SORT_THRESHOLD {
+        (
+            par_sort_links(slots, links, head, mid, 0, compare),
+            par_sort_links(slots, links, rest, len - mid, 0, compare),
+        )
+    } else {
+        std::thread::scope(|scope| {
+            let right =
+                scope.spawn(|| par_sort_links(slots, links, rest, len - mid, depth - 1, compare));
+            let left = par_sort_links(slots, links, head, mid, depth - 1, compare);
+
+            (left, right.join().unwrap())
+        })
+    };
+
+    merge_links(slots, links, left, right, compare)
+}
+
+impl<T: PartialEq, A: Allocator + Clone> LinkedList<T, A> {
+    /// Check if the linked list contains a value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|x| x == value)
+    }
+
+    /// Remove consecutive equal values, keeping the first of each run.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> LinkedList<T, A> {
+    /// Resize the linked list to `len` nodes, filling new nodes at the end
+    /// with copies of `value`.
+    pub fn resize(&mut self, len: usize, value: T) {
+        self.resize_with(len, || value.clone());
+    }
+}
+
 impl<T> Default for LinkedList<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl<T: Clone, A: Allocator + Clone> Clone for LinkedList<T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            arena: self.arena.clone(),
+            id: next_list_id(),
+            generation: self.generation,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug, A: Allocator + Clone> std::fmt::Debug for LinkedList<T, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator + Clone> Index<usize> for LinkedList<T, A> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        let slot = self
+            .arena
+            .slot_at(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, self.len()));
+
+        self.value(slot)
+    }
+}
+
+impl<T, A: Allocator + Clone> IndexMut<usize> for LinkedList<T, A> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let slot = self
+            .arena
+            .slot_at(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, self.len()));
+
+        self.value_mut(slot)
+    }
+}
+
 impl<T: Clone> From<&[T]> for LinkedList<T> {
     fn from(arr: &[T]) -> Self {
-        arr.to_vec().into()
+        arr.iter().cloned().collect()
     }
 }
 
 impl<T: Clone> From<&mut [T]> for LinkedList<T> {
     fn from(arr: &mut [T]) -> Self {
-        arr.to_vec().into()
+        arr.iter().cloned().collect()
     }
 }
 
 impl<T: Clone, const N: usize> From<&[T; N]> for LinkedList<T> {
     fn from(arr: &[T; N]) -> Self {
-        arr.to_vec().into()
+        arr.iter().cloned().collect()
     }
 }
 
 impl<T: Clone, const N: usize> From<&mut [T; N]> for LinkedList<T> {
     fn from(arr: &mut [T; N]) -> Self {
-        arr.to_vec().into()
+        arr.iter().cloned().collect()
     }
 }
 
 impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
     fn from(arr: [T; N]) -> Self {
-        let vector: Vec<_> = arr.into();
-        vector.into()
+        arr.into_iter().collect()
     }
 }
 
 impl<T> From<Vec<T>> for LinkedList<T> {
-    fn from(mut vector: Vec<T>) -> Self {
-        let mut ll = Self::new();
-
-        while let Some(value) = vector.pop() {
-            ll.push(0, value).unwrap();
-        }
-
-        ll
+    fn from(vector: Vec<T>) -> Self {
+        vector.into_iter().collect()
     }
 }
 
-impl<T: std::fmt::Debug, const N: usize> TryInto<[T; N]> for LinkedList<T> {
+impl<T: std::fmt::Debug, A: Allocator + Clone, const N: usize> TryInto<[T; N]>
+    for LinkedList<T, A>
+{
     type Error = LinkedListError;
 
     fn try_into(self) -> core::result::Result<[T; N], Self::Error> {
-        if self.size == N {
+        if self.len() == N {
             let vector: Vec<_> = self.into();
             Ok(vector.try_into().unwrap())
         } else {
@@ -481,58 +1012,190 @@ impl<T: std::fmt::Debug, const N: usize> TryInto<[T; N]> for LinkedList<T> {
     }
 }
 
-impl<T> Into<Vec<T>> for LinkedList<T> {
-    fn into(mut self) -> Vec<T> {
-        let mut vector = Vec::new();
-
-        while self.size > 0 {
-            vector.push(self.pop_front().unwrap());
-        }
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for Vec<T> {
+    fn from(ll: LinkedList<T, A>) -> Self {
+        let mut vector = Vec::with_capacity(ll.len());
+        vector.extend(ll);
 
         vector
     }
 }
 
-/// An iterator over a linked list.
-pub struct IntoIter<T>(LinkedList<T>);
+impl<T> From<VecDeque<T>> for LinkedList<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for VecDeque<T> {
+    fn from(ll: LinkedList<T, A>) -> Self {
+        let mut deque = VecDeque::with_capacity(ll.len());
+        deque.extend(ll);
+
+        deque
+    }
+}
+
+impl<T> From<std::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(std_ll: std::collections::LinkedList<T>) -> Self {
+        std_ll.into_iter().collect()
+    }
+}
+
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for std::collections::LinkedList<T> {
+    fn from(ll: LinkedList<T, A>) -> Self {
+        ll.into_iter().collect()
+    }
+}
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, B: Allocator + Clone> From<linkedlist::LinkedList<T, B>> for LinkedList<T> {
+    fn from(sll: linkedlist::LinkedList<T, B>) -> Self {
+        sll.into_iter().collect()
+    }
+}
+
+/// An iterator that moves the elements out of a linked list.
+pub struct IntoIter<T, A: Allocator + Clone = Global>(LinkedList<T, A>);
+
+impl<T, A: Allocator + Clone> Iterator for IntoIter<T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
         self.0.pop_front().ok()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Allocator + Clone> DoubleEndedIterator for IntoIter<T, A> {
     fn next_back(&mut self) -> Option<T> {
         self.0.pop_back().ok()
     }
 }
 
+impl<T, A: Allocator + Clone> ExactSizeIterator for IntoIter<T, A> {}
+
 impl<T> FromIterator<T> for LinkedList<T> {
     fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
-        Vec::from_iter(iter).into()
+        let mut ll = Self::new();
+        ll.extend(iter);
+        ll
+    }
+}
+
+impl<T, A: Allocator + Clone> Extend<T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
     }
 }
 
-impl<T> IntoIterator for LinkedList<T> {
+impl<T, A: Allocator + Clone> IntoIterator for LinkedList<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter(self)
     }
 }
 
-impl<T: PartialEq> PartialEq for LinkedList<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.size != other.size {
-            false
-        } else if self.size == 0 {
-            true
-        } else {
-            *self.head.as_ref().unwrap().borrow() == *other.head.as_ref().unwrap().borrow()
-        }
+impl<'a, T, A: Allocator + Clone> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator + Clone> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, B: Allocator + Clone> PartialEq<LinkedList<U, B>>
+    for LinkedList<T, A>
+{
+    fn eq(&self, other: &LinkedList<U, B>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, B: Allocator + Clone>
+    PartialEq<linkedlist::LinkedList<U, B>> for LinkedList<T, A>
+{
+    fn eq(&self, other: &linkedlist::LinkedList<U, B>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<[U]> for LinkedList<T, A> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<&[U]> for LinkedList<T, A> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, const N: usize> PartialEq<[U; N]>
+    for LinkedList<T, A>
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<Vec<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<VecDeque<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for [T] {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for &[T] {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, const N: usize> PartialEq<LinkedList<U, A>>
+    for [T; N]
+{
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for Vec<T> {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for VecDeque<T> {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
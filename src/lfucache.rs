@@ -0,0 +1,353 @@
+use crate::doublylinkedlist::{self, LinkedList, NodeHandle};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The keys that have been used a given number of times.
+struct Bucket<K> {
+    /// The number of times the keys have been used.
+    frequency: usize,
+    /// The keys in recency order, most recently used first.
+    keys: LinkedList<K>,
+}
+
+/// A cached value along with handles to its key's bucket and node.
+struct Entry<K, V> {
+    /// The cached value.
+    value: V,
+    /// The bucket holding the key.
+    bucket: NodeHandle<Bucket<K>>,
+    /// The key's node in the bucket.
+    node: NodeHandle<K>,
+}
+
+/// A cache that evicts the least frequently used entry once it is full. Ties
+/// are broken by evicting the least recently used entry.
+pub struct LfuCache<K, V> {
+    /// The cached entries.
+    map: HashMap<K, Entry<K, V>>,
+    /// The non-empty buckets in increasing order of frequency.
+    buckets: LinkedList<Bucket<K>>,
+    /// The maximum number of entries.
+    capacity: usize,
+}
+
+impl<K: Clone + Hash + Eq, V> LfuCache<K, V> {
+    /// Create an empty cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            buckets: LinkedList::new(),
+            capacity,
+        }
+    }
+
+    /// Get the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Get the maximum number of entries in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Move an entry's key into the bucket for the next frequency.
+    fn touch(buckets: &mut LinkedList<Bucket<K>>, entry: &mut Entry<K, V>) {
+        let bucket = buckets.handle_value_mut(&entry.bucket).unwrap();
+        let key = bucket.keys.remove_handle(&entry.node).unwrap();
+        let (frequency, emptied) = (bucket.frequency + 1, bucket.keys.is_empty());
+
+        let next = buckets
+            .next_handle(&entry.bucket)
+            .unwrap()
+            .filter(|next| buckets.handle_value(next).unwrap().frequency == frequency);
+        let next = match next {
+            Some(next) => next,
+            None => {
+                let bucket = Bucket {
+                    frequency,
+                    keys: LinkedList::new(),
+                };
+                buckets.insert_after_handle(&entry.bucket, bucket).unwrap()
+            }
+        };
+
+        if emptied {
+            buckets.remove_handle(&entry.bucket).unwrap();
+        }
+
+        entry.node = buckets
+            .handle_value_mut(&next)
+            .unwrap()
+            .keys
+            .push_front_handle(key);
+        entry.bucket = next;
+    }
+
+    /// Get a reference to a value, counting it as used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.get_mut(key)?;
+        Self::touch(&mut self.buckets, entry);
+
+        Some(&entry.value)
+    }
+
+    /// Get a mutable reference to a value, counting it as used.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.get_mut(key)?;
+        Self::touch(&mut self.buckets, entry);
+
+        Some(&mut entry.value)
+    }
+
+    /// Get a reference to a value without counting it as used.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|entry| &entry.value)
+    }
+
+    /// Get the entry that would be evicted next without counting it as used.
+    pub fn peek_lfu(&self) -> Option<(&K, &V)> {
+        let bucket = self.buckets.front()?;
+        let (key, entry) = self.map.get_key_value(bucket.keys.back()?)?;
+
+        Some((key, &entry.value))
+    }
+
+    /// Get the number of times a key has been used, including its insertion.
+    pub fn frequency<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.get(key)?;
+
+        Some(self.buckets.handle_value(&entry.bucket).ok()?.frequency)
+    }
+
+    /// Check if the cache contains a key, without counting it as used.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Insert an entry. If the key was already present, it is counted as used
+    /// and its old entry is returned. Otherwise, if the cache was full, the
+    /// evicted least frequently used entry is returned.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(entry) = self.map.get_mut(&key) {
+            Self::touch(&mut self.buckets, entry);
+            let old_value = std::mem::replace(&mut entry.value, value);
+
+            return Some((key, old_value));
+        }
+
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+
+        let evicted = if self.map.len() >= self.capacity {
+            self.pop_lfu()
+        } else {
+            None
+        };
+
+        let bucket = self
+            .buckets
+            .front_handle()
+            .filter(|front| self.buckets.handle_value(front).unwrap().frequency == 1)
+            .unwrap_or_else(|| {
+                self.buckets.push_front_handle(Bucket {
+                    frequency: 1,
+                    keys: LinkedList::new(),
+                })
+            });
+        let node = self
+            .buckets
+            .handle_value_mut(&bucket)
+            .unwrap()
+            .keys
+            .push_front_handle(key.clone());
+        self.map.insert(
+            key,
+            Entry {
+                value,
+                bucket,
+                node,
+            },
+        );
+
+        evicted
+    }
+
+    /// Remove an entry, returning its value.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.remove(key)?;
+        let bucket = self.buckets.handle_value_mut(&entry.bucket).unwrap();
+        bucket.keys.remove_handle(&entry.node).unwrap();
+        let emptied = bucket.keys.is_empty();
+
+        if emptied {
+            self.buckets.remove_handle(&entry.bucket).unwrap();
+        }
+
+        Some(entry.value)
+    }
+
+    /// Remove the least frequently used entry, returning it. Among entries
+    /// used equally often, the least recently used one is removed.
+    pub fn pop_lfu(&mut self) -> Option<(K, V)> {
+        let bucket = self.buckets.front_mut()?;
+        let key = bucket.keys.pop_back().ok();
+        let emptied = bucket.keys.is_empty();
+
+        if emptied {
+            self.buckets.pop_front().unwrap();
+        }
+
+        let key = key?;
+        let entry = self.map.remove(&key)?;
+
+        Some((key, entry.value))
+    }
+
+    /// Change the capacity of the cache, evicting the least frequently used
+    /// entries if it holds more than the new capacity.
+    pub fn resize(&mut self, capacity: usize) {
+        while self.map.len() > capacity {
+            self.pop_lfu();
+        }
+
+        self.capacity = capacity;
+    }
+
+    /// Clear the cache.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.buckets.clear();
+    }
+
+    /// Returns an iterator over the entries, most frequently used first.
+    /// Among entries used equally often, the most recently used one comes
+    /// first, so the last entry is the one that would be evicted next.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            buckets: self.buckets.iter(),
+            keys: None,
+            map: &self.map,
+            remaining: self.map.len(),
+        }
+    }
+
+    /// Returns an iterator over the keys, most frequently used first.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    /// Returns an iterator over the values, most frequently used first.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+/// An iterator over the entries of an LFU cache, most frequently used first.
+pub struct Iter<'a, K, V> {
+    /// The buckets not yet visited, walked from the highest frequency.
+    buckets: doublylinkedlist::Iter<'a, Bucket<K>>,
+    /// The keys left in the current bucket, in recency order.
+    keys: Option<doublylinkedlist::Iter<'a, K>>,
+    /// The cached entries.
+    map: &'a HashMap<K, Entry<K, V>>,
+    /// The number of entries not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(key) = self.keys.as_mut().and_then(|keys| keys.next()) {
+                let (key, entry) = self.map.get_key_value(key)?;
+                self.remaining -= 1;
+
+                return Some((key, &entry.value));
+            }
+
+            self.keys = Some(self.buckets.next_back()?.keys.iter());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+/// An iterator over the keys of an LFU cache, most frequently used first.
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values of an LFU cache, most frequently used first.
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: Clone + Hash + Eq, V> IntoIterator for &'a LfuCache<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
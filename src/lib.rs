@@ -1,11 +1,44 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 #![forbid(unsafe_code)]
 
+extern crate alloc;
+
+mod arena;
+#[cfg(feature = "futures")]
+pub mod asyncqueue;
+#[cfg(feature = "std")]
+pub mod boundedlist;
+#[cfg(feature = "std")]
+pub mod channel;
+#[cfg(feature = "std")]
+pub mod circularlist;
+#[cfg(feature = "std")]
 pub mod doublylinkedlist;
+#[cfg(feature = "std")]
+pub mod lfucache;
+#[cfg(feature = "std")]
+pub mod linkedhashmap;
+#[cfg(feature = "std")]
+pub mod linkedhashset;
 pub mod linkedlist;
+#[cfg(feature = "std")]
+pub mod lrucache;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod pooledlist;
+pub mod skiplist;
+#[cfg(feature = "std")]
+pub mod sortedlist;
+pub mod staticlist;
+pub mod unrolledlist;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod ll1_tests {
+    use super::doublylinkedlist;
     use super::linkedlist::*;
+    use std::collections::VecDeque;
+    use std::ops::Bound;
 
     #[test]
     fn test_new() {
@@ -82,81 +115,81 @@ mod ll1_tests {
     #[test]
     fn test_push() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.push(1, 0).is_err());
         ll1.push(0, 3).unwrap();
-        assert_eq!(ll1, [3].into());
+        assert_eq!(ll1, LinkedList::from([3]));
         ll1.push(0, 2).unwrap();
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.push(2, 7).unwrap();
-        assert_eq!(ll1, [2, 3, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 7]));
         ll1.push(2, 5).unwrap();
-        assert_eq!(ll1, [2, 3, 5, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5, 7]));
     }
 
     #[test]
     fn test_push_front() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.push_front(5);
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.push_front(3);
-        assert_eq!(ll1, [3, 5].into());
+        assert_eq!(ll1, LinkedList::from([3, 5]));
         ll1.push_front(2);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
     }
 
     #[test]
     fn test_push_back() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.push_back(2);
-        assert_eq!(ll1, [2].into());
+        assert_eq!(ll1, LinkedList::from([2]));
         ll1.push_back(3);
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.push_back(5);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
     }
 
     #[test]
     fn test_pop() {
         let mut ll1 = LinkedList::from([2, 3, 5, 7]);
-        assert_eq!(ll1, [2, 3, 5, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5, 7]));
         assert!(ll1.pop(4).is_err());
         ll1.pop(3).unwrap();
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop(1).unwrap();
-        assert_eq!(ll1, [2, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 5]));
         ll1.pop(0).unwrap();
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.pop(0).unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop(0).is_err());
     }
 
     #[test]
     fn test_pop_front() {
         let mut ll1 = LinkedList::from([2, 3, 5]);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [3, 5].into());
+        assert_eq!(ll1, LinkedList::from([3, 5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop_front().is_err());
     }
 
     #[test]
     fn test_pop_back() {
         let mut ll1 = LinkedList::from([2, 3, 5]);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [2].into());
+        assert_eq!(ll1, LinkedList::from([2]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop_back().is_err());
     }
 
@@ -165,50 +198,50 @@ mod ll1_tests {
         let mut ll1 = LinkedList::<i32>::new();
         ll1.clear();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
 
         let mut ll2 = LinkedList::from([2, 3, 5, 7]);
         assert_eq!(ll2.len(), 4);
-        assert_eq!(ll2, [2, 3, 5, 7].into());
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
         ll2.clear();
         assert_eq!(ll2.len(), 0);
-        assert_eq!(ll2, [].into());
+        assert_eq!(ll2, LinkedList::<i32>::from([]));
     }
 
     #[test]
     fn test_reverse() {
         let mut ll1 = LinkedList::<i32>::new();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.reverse();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
 
         let mut ll2 = LinkedList::from([1]);
         assert_eq!(ll2.len(), 1);
-        assert_eq!(ll2, [1].into());
+        assert_eq!(ll2, LinkedList::from([1]));
         ll2.reverse();
         assert_eq!(ll2.len(), 1);
-        assert_eq!(ll2, [1].into());
+        assert_eq!(ll2, LinkedList::from([1]));
 
         let mut ll3 = LinkedList::from([2, 3]);
         assert_eq!(ll3.len(), 2);
-        assert_eq!(ll3, [2, 3].into());
+        assert_eq!(ll3, LinkedList::from([2, 3]));
         ll3.reverse();
         assert_eq!(ll3.len(), 2);
-        assert_eq!(ll3, [3, 2].into());
+        assert_eq!(ll3, LinkedList::from([3, 2]));
 
         let mut ll4 = LinkedList::from([4, 5, 6]);
         assert_eq!(ll4.len(), 3);
-        assert_eq!(ll4, [4, 5, 6].into());
+        assert_eq!(ll4, LinkedList::from([4, 5, 6]));
         ll4.reverse();
         assert_eq!(ll4.len(), 3);
-        assert_eq!(ll4, [6, 5, 4].into());
+        assert_eq!(ll4, LinkedList::from([6, 5, 4]));
 
         let mut ll2 = LinkedList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
-        assert_eq!(ll2, [2, 3, 5, 7, 11, 13, 17, 19, 23].into());
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]));
         ll2.reverse();
-        assert_eq!(ll2, [23, 19, 17, 13, 11, 7, 5, 3, 2].into());
+        assert_eq!(ll2, LinkedList::from([23, 19, 17, 13, 11, 7, 5, 3, 2]));
     }
 
     #[test]
@@ -242,14 +275,14 @@ mod ll1_tests {
 
         let ll2_iter = ll2.iter_mut();
         ll2_iter.for_each(|n| *n += 1);
-        assert_eq!(ll2, [3, 4, 6, 8].into());
+        assert_eq!(ll2, LinkedList::from([3, 4, 6, 8]));
     }
 
     #[test]
     fn test_default() {
         let ll1 = LinkedList::<i32>::new();
         let ll2 = LinkedList::default();
-        let ll3 = Default::default();
+        let ll3: LinkedList<i32> = Default::default();
         assert_eq!(ll1, ll2);
         assert_eq!(ll1, ll3);
         assert_eq!(ll2.len(), 0);
@@ -526,7 +559,7 @@ mod ll1_tests {
             LinkedList::from([2, 3, 5, 7]),
             LinkedList::from([2, 3, 5, 7])
         );
-        assert_ne!(LinkedList::from([]), LinkedList::from([2, 3, 5, 7]));
+        assert_ne!(LinkedList::<i32>::from([]), LinkedList::from([2, 3, 5, 7]));
         assert_ne!(
             LinkedList::from([1, 2, 3, 4]),
             LinkedList::from([2, 3, 5, 7])
@@ -540,17 +573,513 @@ mod ll1_tests {
             LinkedList::from([2, 3, 5, 7])
         );
     }
+
+    #[test]
+    fn test_partial_eq_other_types() {
+        let ll1 = LinkedList::from([2, 3, 5, 7]);
+        assert_eq!(ll1, vec![2, 3, 5, 7]);
+        assert_eq!(ll1, [2, 3, 5, 7]);
+        assert_eq!(ll1, [2, 3, 5, 7][..]);
+        assert_eq!(ll1, &[2, 3, 5, 7][..]);
+        assert_eq!(ll1, VecDeque::from([2, 3, 5, 7]));
+        assert_eq!(ll1, doublylinkedlist::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(vec![2, 3, 5, 7], ll1);
+        assert_eq!([2, 3, 5, 7], ll1);
+        assert_eq!([2, 3, 5, 7][..], ll1);
+        assert_eq!(&[2, 3, 5, 7][..], ll1);
+        assert_eq!(VecDeque::from([2, 3, 5, 7]), ll1);
+        assert_ne!(ll1, vec![2, 3, 5]);
+        assert_ne!(ll1, [2, 3, 5, 8]);
+        assert_ne!(VecDeque::from([2, 3, 5, 7, 11]), ll1);
+        assert_ne!(ll1, doublylinkedlist::LinkedList::from([7, 5, 3, 2]));
+
+        let ll2 = LinkedList::from(["a", "b"]);
+        assert_eq!(ll2, LinkedList::from(["a".to_owned(), "b".to_owned()]));
+        assert_eq!(ll2, vec!["a".to_owned(), "b".to_owned()]);
+        assert_ne!(ll2, LinkedList::from(["a".to_owned()]));
+    }
+
+    #[test]
+    fn test_from_doubly_linked_list() {
+        let ll1: LinkedList<i32> = doublylinkedlist::LinkedList::new().into();
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(doublylinkedlist::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let ll3 = LinkedList::from(doublylinkedlist::LinkedList::from([
+            String::from("a"),
+            String::from("b"),
+        ]));
+        assert_eq!(["a", "b"], ll3);
+    }
+
+    #[test]
+    fn test_vec_deque_conversions() {
+        let ll1 = LinkedList::<i32>::from(VecDeque::new());
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(VecDeque::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let deque: VecDeque<_> = ll2.into();
+        assert_eq!(deque, VecDeque::from([2, 3, 5, 7]));
+    }
+
+    #[test]
+    fn test_std_linked_list_conversions() {
+        let ll1 = LinkedList::<i32>::from(std::collections::LinkedList::new());
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(std::collections::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let std_ll: std::collections::LinkedList<_> = ll2.into();
+        assert_eq!(std_ll, std::collections::LinkedList::from([2, 3, 5, 7]));
+    }
+
+    #[test]
+    fn test_std_accessors() {
+        let mut ll1 = LinkedList::<i32>::new();
+        assert_eq!(ll1.front(), None);
+        assert_eq!(ll1.back(), None);
+        assert_eq!(ll1.first(), None);
+        assert_eq!(ll1.last(), None);
+        assert_eq!(ll1.get(0), None);
+        assert_eq!(ll1.back_mut(), None);
+
+        ll1 = LinkedList::from([2, 3, 5]);
+        assert_eq!(ll1.front(), Some(&2));
+        assert_eq!(ll1.back(), Some(&5));
+        assert_eq!(ll1.first(), Some(&2));
+        assert_eq!(ll1.last(), Some(&5));
+        assert_eq!(ll1.get(1), Some(&3));
+        assert_eq!(ll1.get(3), None);
+        *ll1.front_mut().unwrap() = 1;
+        *ll1.back_mut().unwrap() = 7;
+        assert_eq!([1, 3, 7], ll1);
+        assert!(ll1.contains(&3));
+        assert!(!ll1.contains(&5));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        ll1.swap(0, 4);
+        assert_eq!([5, 2, 3, 4, 1], ll1);
+        ll1.swap(3, 1);
+        assert_eq!([5, 4, 3, 2, 1], ll1);
+        ll1.swap(2, 2);
+        assert_eq!([5, 4, 3, 2, 1], ll1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the index is 3")]
+    fn test_swap_out_of_bounds() {
+        let mut ll1 = LinkedList::from([1, 2, 3]);
+        ll1.swap(0, 3);
+    }
+
+    #[test]
+    fn test_truncate_resize() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        ll1.truncate(10);
+        assert_eq!(ll1.len(), 5);
+        ll1.truncate(2);
+        assert_eq!([1, 2], ll1);
+        ll1.resize(4, 0);
+        assert_eq!([1, 2, 0, 0], ll1);
+        assert_eq!(ll1.len(), 4);
+        ll1.resize(1, 0);
+        assert_eq!([1], ll1);
+        ll1.truncate(0);
+        assert!(ll1.is_empty());
+
+        let mut n = 0;
+        ll1.resize_with(3, || {
+            n += 1;
+            n
+        });
+        assert_eq!([1, 2, 3], ll1);
+        ll1.push_back(4);
+        assert_eq!([1, 2, 3, 4], ll1);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        ll1.rotate_left(2);
+        assert_eq!([3, 4, 5, 1, 2], ll1);
+        ll1.rotate_right(2);
+        assert_eq!([1, 2, 3, 4, 5], ll1);
+        ll1.rotate_left(5);
+        assert_eq!([1, 2, 3, 4, 5], ll1);
+        ll1.rotate_right(1);
+        assert_eq!([5, 1, 2, 3, 4], ll1);
+        ll1.push_back(6);
+        assert_eq!([5, 1, 2, 3, 4, 6], ll1);
+
+        let mut ll2 = LinkedList::<i32>::new();
+        ll2.rotate_left(0);
+        assert!(ll2.is_empty());
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(ll1.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+
+        let mut iter = ll1.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        for value in ll1.iter_mut().rev().take(2) {
+            *value *= 10;
+        }
+        assert_eq!([1, 2, 30, 40], ll1);
+        assert_eq!(
+            ll1.into_iter().rev().collect::<Vec<_>>(),
+            vec![40, 30, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut ll1 = LinkedList::from([2, 3, 5]);
+        *ll1.get_mut(1).unwrap() = 4;
+        assert_eq!([2, 4, 5], ll1);
+        assert_eq!(ll1.get_mut(3), None);
+        assert_eq!(
+            ll1.get_value(3).unwrap_err().to_string(),
+            "index out of bounds; linked list size: 3, provided index: 3"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 5")]
+    fn test_index_out_of_bounds() {
+        let ll1 = LinkedList::from([2, 3, 5]);
+        let _ = ll1[5];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1[0] = 1;
+    }
+
+    #[test]
+    fn test_try_push() {
+        #[derive(Debug, PartialEq)]
+        struct Token(u32);
+
+        let mut ll1 = LinkedList::new();
+        assert!(ll1.try_push(0, Token(1)).is_ok());
+        assert!(ll1.try_push(1, Token(3)).is_ok());
+        assert!(ll1.try_push(1, Token(2)).is_ok());
+
+        let (error, token) = ll1.try_push(4, Token(4)).unwrap_err();
+        assert!(matches!(error, LinkedListError::IndexOutOfBounds { .. }));
+        assert_eq!(token, Token(4));
+
+        assert!(ll1.try_set_value(0, Token(0)).is_ok());
+        let (_, token) = ll1.try_set_value(3, token).unwrap_err();
+        assert_eq!(token, Token(4));
+        assert_eq!([Token(0), Token(2), Token(3)], ll1);
+    }
+
+    #[test]
+    fn test_range() {
+        let ll1 = LinkedList::from([1, 2, 3, 4, 5, 6]);
+        let range = ll1.range(1..4).unwrap();
+        assert_eq!(range.len(), 3);
+        assert!(!range.is_empty());
+        assert_eq!(range.get(0), Some(&2));
+        assert_eq!(range.get(2), Some(&4));
+        assert_eq!(range.get(3), None);
+        assert_eq!(range.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(range.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2]);
+        assert_eq!(range.iter().len(), 3);
+
+        assert_eq!(ll1.range(..).unwrap().len(), 6);
+        assert_eq!(
+            ll1.range(4..).unwrap().into_iter().collect::<Vec<_>>(),
+            vec![&5, &6]
+        );
+        assert_eq!(
+            ll1.range(..=1).unwrap().into_iter().collect::<Vec<_>>(),
+            vec![&1, &2]
+        );
+        assert!(ll1.range(6..6).unwrap().is_empty());
+        assert_eq!(ll1.range(6..6).unwrap().iter().next_back(), None);
+
+        assert!(matches!(
+            ll1.range(2..7),
+            Err(LinkedListError::InvalidRange {
+                start: 2,
+                end: 7,
+                size: 6
+            })
+        ));
+        assert!(matches!(
+            ll1.range((Bound::Included(4), Bound::Excluded(2))),
+            Err(LinkedListError::InvalidRange {
+                start: 4,
+                end: 2,
+                size: 6
+            })
+        ));
+        assert!(ll1.range(..=usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_range_mut() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5, 6]);
+        let mut range = ll1.range_mut(2..5).unwrap();
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.get(1), Some(&4));
+        *range.get_mut(0).unwrap() = 30;
+        assert_eq!(range.get_mut(3), None);
+
+        for value in range.iter_mut() {
+            *value += 1;
+        }
+        if let Some(value) = range.iter_mut().next_back() {
+            *value *= 10;
+        }
+        assert_eq!(range.iter().collect::<Vec<_>>(), vec![&31, &5, &60]);
+
+        for value in ll1.range_mut(..2).unwrap() {
+            *value = 0;
+        }
+        assert_eq!([0, 0, 31, 5, 60, 6], ll1);
+        assert!(ll1.range_mut(7..).is_err());
+    }
+
+    #[test]
+    fn test_split_at_mut() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        let (prefix, suffix) = ll1.split_at_mut(2);
+        assert_eq!(prefix.len(), 2);
+        assert_eq!(suffix.len(), 3);
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || prefix.for_each(|value| *value *= 10));
+            scope.spawn(move || suffix.rev().for_each(|value| *value += 100));
+        });
+        assert_eq!([10, 20, 103, 104, 105], ll1);
+
+        let (prefix, suffix) = ll1.split_at_mut(0);
+        assert_eq!(prefix.count(), 0);
+        assert_eq!(suffix.count(), 5);
+        let (prefix, mut suffix) = ll1.split_at_mut(5);
+        assert_eq!(
+            prefix.rev().map(|value| *value).collect::<Vec<_>>(),
+            vec![105, 104, 103, 20, 10]
+        );
+        assert_eq!(suffix.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "mid > len")]
+    fn test_split_at_mut_out_of_bounds() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        let _ = ll1.split_at_mut(6);
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        let bump = bumpalo::Bump::with_capacity(1 << 16);
+        let mut ll = LinkedList::new_in(&bump);
+        assert!(std::ptr::eq(*ll.allocator(), &bump));
+
+        let allocations = allocation_counter::measure(|| {
+            for i in 0..100 {
+                ll.push_back(i);
+            }
+            ll.push(50, -1).unwrap();
+            ll.reverse();
+            ll.pop(10).unwrap();
+        });
+        assert_eq!(allocations.count_total, 0);
+        assert!(bump.allocated_bytes() > 0);
+
+        assert_eq!(ll.len(), 100);
+        assert_eq!(ll[0], 99);
+        assert_eq!(ll.iter().filter(|&&value| value == -1).count(), 1);
+
+        let values: Vec<_> = ll.into_iter().collect();
+        assert_eq!(values.len(), 100);
+        assert_eq!(values[..3], [99, 98, 97]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1.sort();
+        assert!(ll1.is_empty());
+
+        let mut ll2 = LinkedList::from([5, 2, 7, 3, 2, 0]);
+        ll2.sort();
+        assert_eq!([0, 2, 2, 3, 5, 7], ll2);
+        assert_eq!(ll2.len(), 6);
+
+        let mut ll3 = LinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        ll3.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!([(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')], ll3);
+
+        let mut ll4: LinkedList<_> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+        let allocations = allocation_counter::measure(|| ll4.sort_by(|a, b| b.cmp(a)));
+        assert_eq!(allocations.count_total, 0);
+        assert!(ll4.iter().copied().eq((0..1000).rev()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_par_sort() {
+        let values: Vec<_> = (0..10_000u64)
+            .map(|i| (i.wrapping_mul(2_654_435_761) % 1000, i))
+            .collect();
+        let mut ll1: LinkedList<_> = values.iter().copied().collect();
+        ll1.par_sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut sorted = values.clone();
+        sorted.sort_by_key(|value| value.0);
+        assert_eq!(sorted, ll1);
+
+        let mut ll2: LinkedList<_> = values.iter().map(|value| value.1).rev().collect();
+        ll2.par_sort();
+        assert!(ll2.iter().copied().eq(0..10_000));
+
+        let mut ll3 = LinkedList::from([3, 1, 2]);
+        ll3.par_sort();
+        assert_eq!([1, 2, 3], ll3);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let a = || LinkedList::from([1, 2, 2, 4, 6, 8]);
+        let b = || LinkedList::from([2, 3, 4, 4, 8, 9]);
+
+        let merged = a().merge(b());
+        assert_eq!([1, 2, 2, 2, 3, 4, 4, 4, 6, 8, 8, 9], merged);
+        assert_eq!(merged.len(), 12);
+        assert_eq!([1, 2, 2, 3, 4, 4, 6, 8, 9], a().union(b()));
+        assert_eq!([2, 4, 8], a().intersection(b()));
+        assert_eq!([1, 2, 6], a().difference(b()));
+        assert_eq!([3, 4, 9], b().difference(a()));
+        assert_eq!([1, 2, 3, 4, 6, 9], a().symmetric_difference(b()));
+
+        let difference = a().difference(a());
+        assert!(difference.is_empty());
+        assert_eq!(difference.len(), 0);
+        assert_eq!(a().union(LinkedList::new()), a());
+        assert!(LinkedList::new().intersection(b()).is_empty());
+
+        let ll1 = LinkedList::from([(1, 'a'), (2, 'b')]);
+        let ll2 = LinkedList::from([(1, 'a'), (1, 'c')]);
+        assert_eq!([(1, 'a'), (1, 'a'), (1, 'c'), (2, 'b')], ll1.merge(ll2));
+
+        let ll3: LinkedList<_> = (0..1000).step_by(2).collect();
+        let ll4: LinkedList<_> = (0..1000).step_by(3).collect();
+        let (ll5, ll6) = (ll3.clone(), ll4.clone());
+        let (ll7, ll8) = (ll3.clone(), ll4.clone());
+        let (ll9, ll10) = (ll3.clone(), ll4.clone());
+        let allocations = allocation_counter::measure(|| {
+            assert_eq!(ll5.union(ll6).len(), 667);
+            assert_eq!(ll7.intersection(ll8).len(), 167);
+            assert_eq!(ll9.symmetric_difference(ll10).len(), 500);
+        });
+        assert_eq!(allocations.count_total, 0);
+
+        // Both inline first nodes are kept, so one of them needs a new node.
+        let allocations = allocation_counter::measure(|| {
+            let merged = ll3.merge(ll4);
+            assert_eq!(merged.len(), 834);
+        });
+        assert_eq!(allocations.count_total, 1);
+    }
+
+    #[test]
+    fn test_merge_all() {
+        let merged = LinkedList::merge_all([
+            LinkedList::from([1, 4, 7]),
+            LinkedList::new(),
+            LinkedList::from([2, 5, 8]),
+            LinkedList::from([0, 3, 6, 9]),
+        ]);
+        assert_eq!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9], merged);
+        assert_eq!(merged.len(), 10);
+
+        let merged = LinkedList::merge_all([
+            LinkedList::from([(1, 'a'), (2, 'a')]),
+            LinkedList::from([(1, 'b')]),
+            LinkedList::from([(0, 'c'), (1, 'c')]),
+        ]);
+        assert_eq!([(0, 'c'), (1, 'a'), (1, 'b'), (1, 'c'), (2, 'a')], merged);
+
+        assert!(LinkedList::<i32>::merge_all([]).is_empty());
+
+        let bump = bumpalo::Bump::new();
+        let mut lists = [LinkedList::new_in(&bump), LinkedList::new_in(&bump)];
+        lists[0].push_back_all([1, 3]);
+        lists[1].push_back_all([2, 4]);
+        let mut merged = LinkedList::merge_all_in(lists, &bump);
+        merged.push_back(5);
+        assert!(std::ptr::eq(*merged.allocator(), &bump));
+        assert_eq!([1, 2, 3, 4, 5], merged);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1.dedup();
+        assert!(ll1.is_empty());
+
+        let mut ll2 = LinkedList::from([1, 1, 2, 3, 3, 3, 1, 4, 4]);
+        ll2.dedup();
+        assert_eq!([1, 2, 3, 1, 4], ll2);
+        assert_eq!(ll2.len(), 5);
+        assert_eq!(ll2.back(), Some(&4));
+
+        let mut ll3 = LinkedList::from([10, 11, 20, 25, 31, 12]);
+        ll3.dedup_by_key(|value| *value / 10);
+        assert_eq!([10, 20, 31, 12], ll3);
+
+        let mut ll4 = LinkedList::from(["a", "A", "b", "B", "b", "c"]);
+        let removed = ll4.dedup_by_drain(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(["a", "b", "c"], ll4);
+        assert_eq!(ll4.len(), 3);
+        assert_eq!(removed, vec!["A", "B", "b"]);
+
+        let mut ll5 = LinkedList::from([5, 5, 5]);
+        let allocations = allocation_counter::measure(|| ll5.dedup());
+        assert_eq!(allocations.count_total, 0);
+        assert_eq!([5], ll5);
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod ll2_tests {
     use super::doublylinkedlist::*;
+    use super::linkedlist;
+    use std::collections::VecDeque;
 
     #[test]
     fn test_new() {
         let ll1 = LinkedList::<i32>::new();
         assert_eq!(ll1.len(), 0);
-        assert!(ll1.get(0).is_err());
+        assert!(ll1.get(0).is_none());
     }
 
     #[test]
@@ -581,12 +1110,17 @@ mod ll2_tests {
     #[test]
     fn test_get() {
         let ll1 = LinkedList::<i32>::from([]);
-        assert!(ll1.get(0).is_err());
+        assert!(ll1.get(0).is_none());
 
-        let ll2 = LinkedList::from([2, 3, 5]);
-        assert_eq!(ll2.get(0).unwrap(), 2);
-        assert_eq!(ll2.get(1).unwrap(), 3);
-        assert_eq!(ll2.get(2).unwrap(), 5);
+        let mut ll2 = LinkedList::from([2, 3, 5]);
+        assert_eq!(*ll2.get(0).unwrap(), 2);
+        assert_eq!(*ll2.get(1).unwrap(), 3);
+        assert_eq!(*ll2.get(2).unwrap(), 5);
+        assert!(ll2.get(3).is_none());
+
+        *ll2.get_mut(1).unwrap() = 4;
+        assert!(ll2.get_mut(3).is_none());
+        assert_eq!(ll2, [2, 4, 5]);
     }
 
     #[test]
@@ -605,81 +1139,81 @@ mod ll2_tests {
     #[test]
     fn test_push() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.push(1, 0).is_err());
         ll1.push(0, 3).unwrap();
-        assert_eq!(ll1, [3].into());
+        assert_eq!(ll1, LinkedList::from([3]));
         ll1.push(0, 2).unwrap();
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.push(2, 7).unwrap();
-        assert_eq!(ll1, [2, 3, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 7]));
         ll1.push(2, 5).unwrap();
-        assert_eq!(ll1, [2, 3, 5, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5, 7]));
     }
 
     #[test]
     fn test_push_front() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.push_front(5);
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.push_front(3);
-        assert_eq!(ll1, [3, 5].into());
+        assert_eq!(ll1, LinkedList::from([3, 5]));
         ll1.push_front(2);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
     }
 
     #[test]
     fn test_push_back() {
         let mut ll1 = LinkedList::new();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.push_back(2);
-        assert_eq!(ll1, [2].into());
+        assert_eq!(ll1, LinkedList::from([2]));
         ll1.push_back(3);
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.push_back(5);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
     }
 
     #[test]
     fn test_pop() {
         let mut ll1 = LinkedList::from([2, 3, 5, 7]);
-        assert_eq!(ll1, [2, 3, 5, 7].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5, 7]));
         assert!(ll1.pop(4).is_err());
         ll1.pop(3).unwrap();
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop(1).unwrap();
-        assert_eq!(ll1, [2, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 5]));
         ll1.pop(0).unwrap();
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.pop(0).unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop(0).is_err());
     }
 
     #[test]
     fn test_pop_front() {
         let mut ll1 = LinkedList::from([2, 3, 5]);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [3, 5].into());
+        assert_eq!(ll1, LinkedList::from([3, 5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [5].into());
+        assert_eq!(ll1, LinkedList::from([5]));
         ll1.pop_front().unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop_front().is_err());
     }
 
     #[test]
     fn test_pop_back() {
         let mut ll1 = LinkedList::from([2, 3, 5]);
-        assert_eq!(ll1, [2, 3, 5].into());
+        assert_eq!(ll1, LinkedList::from([2, 3, 5]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [2, 3].into());
+        assert_eq!(ll1, LinkedList::from([2, 3]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [2].into());
+        assert_eq!(ll1, LinkedList::from([2]));
         ll1.pop_back().unwrap();
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         assert!(ll1.pop_back().is_err());
     }
 
@@ -688,57 +1222,57 @@ mod ll2_tests {
         let mut ll1 = LinkedList::<i32>::new();
         ll1.clear();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
 
         let mut ll2 = LinkedList::from([2, 3, 5, 7]);
         assert_eq!(ll2.len(), 4);
-        assert_eq!(ll2, [2, 3, 5, 7].into());
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
         ll2.clear();
         assert_eq!(ll2.len(), 0);
-        assert_eq!(ll2, [].into());
+        assert_eq!(ll2, LinkedList::<i32>::from([]));
     }
 
     #[test]
     fn test_reverse() {
         let mut ll1 = LinkedList::<i32>::new();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
         ll1.reverse();
         assert_eq!(ll1.len(), 0);
-        assert_eq!(ll1, [].into());
+        assert_eq!(ll1, LinkedList::<i32>::from([]));
 
         let mut ll2 = LinkedList::from([1]);
         assert_eq!(ll2.len(), 1);
-        assert_eq!(ll2, [1].into());
+        assert_eq!(ll2, LinkedList::from([1]));
         ll2.reverse();
         assert_eq!(ll2.len(), 1);
-        assert_eq!(ll2, [1].into());
+        assert_eq!(ll2, LinkedList::from([1]));
 
         let mut ll3 = LinkedList::from([2, 3]);
         assert_eq!(ll3.len(), 2);
-        assert_eq!(ll3, [2, 3].into());
+        assert_eq!(ll3, LinkedList::from([2, 3]));
         ll3.reverse();
         assert_eq!(ll3.len(), 2);
-        assert_eq!(ll3, [3, 2].into());
+        assert_eq!(ll3, LinkedList::from([3, 2]));
 
         let mut ll4 = LinkedList::from([4, 5, 6]);
         assert_eq!(ll4.len(), 3);
-        assert_eq!(ll4, [4, 5, 6].into());
+        assert_eq!(ll4, LinkedList::from([4, 5, 6]));
         ll4.reverse();
         assert_eq!(ll4.len(), 3);
-        assert_eq!(ll4, [6, 5, 4].into());
+        assert_eq!(ll4, LinkedList::from([6, 5, 4]));
 
         let mut ll2 = LinkedList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
-        assert_eq!(ll2, [2, 3, 5, 7, 11, 13, 17, 19, 23].into());
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]));
         ll2.reverse();
-        assert_eq!(ll2, [23, 19, 17, 13, 11, 7, 5, 3, 2].into());
+        assert_eq!(ll2, LinkedList::from([23, 19, 17, 13, 11, 7, 5, 3, 2]));
     }
 
     #[test]
     fn test_default() {
         let ll1 = LinkedList::<i32>::new();
         let ll2 = LinkedList::default();
-        let ll3 = Default::default();
+        let ll3: LinkedList<i32> = Default::default();
         assert_eq!(ll1, ll2);
         assert_eq!(ll1, ll3);
         assert_eq!(ll2.len(), 0);
@@ -955,7 +1489,7 @@ mod ll2_tests {
             LinkedList::from([2, 3, 5, 7]),
             LinkedList::from([2, 3, 5, 7])
         );
-        assert_ne!(LinkedList::from([]), LinkedList::from([2, 3, 5, 7]));
+        assert_ne!(LinkedList::<i32>::from([]), LinkedList::from([2, 3, 5, 7]));
         assert_ne!(
             LinkedList::from([1, 2, 3, 4]),
             LinkedList::from([2, 3, 5, 7])
@@ -969,4 +1503,2888 @@ mod ll2_tests {
             LinkedList::from([2, 3, 5, 7])
         );
     }
+
+    #[test]
+    fn test_partial_eq_other_types() {
+        let ll1 = LinkedList::from([2, 3, 5, 7]);
+        assert_eq!(ll1, vec![2, 3, 5, 7]);
+        assert_eq!(ll1, [2, 3, 5, 7]);
+        assert_eq!(ll1, [2, 3, 5, 7][..]);
+        assert_eq!(ll1, &[2, 3, 5, 7][..]);
+        assert_eq!(ll1, VecDeque::from([2, 3, 5, 7]));
+        assert_eq!(ll1, linkedlist::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(vec![2, 3, 5, 7], ll1);
+        assert_eq!([2, 3, 5, 7], ll1);
+        assert_eq!([2, 3, 5, 7][..], ll1);
+        assert_eq!(&[2, 3, 5, 7][..], ll1);
+        assert_eq!(VecDeque::from([2, 3, 5, 7]), ll1);
+        assert_ne!(ll1, vec![2, 3, 5]);
+        assert_ne!(ll1, [2, 3, 5, 8]);
+        assert_ne!(VecDeque::from([2, 3, 5, 7, 11]), ll1);
+        assert_ne!(ll1, linkedlist::LinkedList::from([7, 5, 3, 2]));
+
+        let ll2 = LinkedList::from(["a", "b"]);
+        assert_eq!(ll2, LinkedList::from(["a".to_owned(), "b".to_owned()]));
+        assert_eq!(ll2, vec!["a".to_owned(), "b".to_owned()]);
+        assert_ne!(ll2, LinkedList::from(["a".to_owned()]));
+    }
+
+    #[test]
+    fn test_from_singly_linked_list() {
+        let ll1: LinkedList<i32> = linkedlist::LinkedList::new().into();
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(linkedlist::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let ll3 = LinkedList::from(linkedlist::LinkedList::from([
+            String::from("a"),
+            String::from("b"),
+        ]));
+        assert_eq!(["a", "b"], ll3);
+    }
+
+    #[test]
+    fn test_vec_deque_conversions() {
+        let ll1 = LinkedList::<i32>::from(VecDeque::new());
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(VecDeque::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let deque: VecDeque<_> = ll2.into();
+        assert_eq!(deque, VecDeque::from([2, 3, 5, 7]));
+    }
+
+    #[test]
+    fn test_std_linked_list_conversions() {
+        let ll1 = LinkedList::<i32>::from(std::collections::LinkedList::new());
+        assert_eq!(ll1, LinkedList::new());
+
+        let ll2 = LinkedList::from(std::collections::LinkedList::from([2, 3, 5, 7]));
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(ll2, LinkedList::from([2, 3, 5, 7]));
+
+        let std_ll: std::collections::LinkedList<_> = ll2.into();
+        assert_eq!(std_ll, std::collections::LinkedList::from([2, 3, 5, 7]));
+    }
+
+    #[test]
+    fn test_front_back() {
+        let ll1 = LinkedList::<i32>::new();
+        assert!(ll1.front().is_none());
+        assert!(ll1.back().is_none());
+
+        let ll2 = LinkedList::from([2, 3, 5, 7]);
+        assert_eq!(*ll2.front().unwrap(), 2);
+        assert_eq!(*ll2.back().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_iter() {
+        let ll1 = LinkedList::<i32>::new();
+        assert_eq!(ll1.iter().next(), None);
+
+        let ll2 = LinkedList::from([2, 3, 5, 7]);
+        let mut ll2_iter = ll2.iter();
+        assert_eq!(ll2_iter.next(), Some(&2));
+        assert_eq!(ll2_iter.next(), Some(&3));
+        assert_eq!(ll2_iter.next(), Some(&5));
+        assert_eq!(ll2_iter.next(), Some(&7));
+        assert_eq!(ll2_iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut ll1 = LinkedList::<i32>::new();
+        assert_eq!(ll1.iter_mut().next(), None);
+
+        let mut ll2 = LinkedList::from([0, 1, 2, 3, 4]);
+        ll2.pop(1).unwrap();
+        ll2.push_front(5);
+        ll2.rotate_left(2);
+        ll2.reverse();
+        assert_eq!(ll2, [0, 5, 4, 3, 2]);
+
+        let mut ll2_iter = ll2.iter_mut();
+        assert_eq!(ll2_iter.len(), 5);
+        assert_eq!(ll2_iter.next(), Some(&mut 0));
+        assert_eq!(ll2_iter.next_back(), Some(&mut 2));
+        *ll2_iter.next().unwrap() = 50;
+        assert_eq!(ll2_iter.len(), 2);
+        assert_eq!(ll2_iter.next_back(), Some(&mut 3));
+        assert_eq!(ll2_iter.next(), Some(&mut 4));
+        assert_eq!(ll2_iter.next(), None);
+        assert_eq!(ll2_iter.next_back(), None);
+
+        for value in &mut ll2 {
+            *value += 1;
+        }
+        assert_eq!(ll2, [1, 51, 5, 4, 3]);
+        ll2.push_front(6);
+        ll2.push_back(7);
+        assert_eq!(ll2, [6, 1, 51, 5, 4, 3, 7]);
+        assert_eq!(ll2.iter_mut().rev().count(), 7);
+    }
+
+    #[test]
+    fn test_get_value() {
+        let mut ll1 = LinkedList::from([2, 3, 5]);
+        assert_eq!(*ll1.get_value(1).unwrap(), 3);
+        assert!(matches!(
+            ll1.get_value(3),
+            Err(LinkedListError::IndexOutOfBounds)
+        ));
+        *ll1.get_value_mut(2).unwrap() = 7;
+        assert!(ll1.get_value_mut(3).is_err());
+        assert_eq!(ll1, [2, 3, 7]);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut ll1 = LinkedList::new();
+        let mut ll2 = LinkedList::from([2, 3]);
+        ll1.append(&mut ll2);
+        assert_eq!([2, 3], ll1);
+        assert!(ll2.is_empty());
+
+        let mut ll3 = LinkedList::from([5, 7]);
+        ll1.append(&mut ll3);
+        assert_eq!([2, 3, 5, 7], ll1);
+        assert_eq!(ll1.len(), 4);
+        assert_eq!(ll3.len(), 0);
+        ll1.append(&mut ll3);
+        assert_eq!([2, 3, 5, 7], ll1);
+        assert_eq!(ll1.pop_back().unwrap(), 7);
+        assert_eq!(ll1.pop_front().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_drop_clone() {
+        let value = std::rc::Rc::new(0);
+        let mut ll1 = LinkedList::new();
+        for _ in 0..4 {
+            ll1.push_back(std::rc::Rc::clone(&value));
+        }
+        let mut ll2 = ll1.clone();
+        ll2.pop_back().unwrap();
+        assert_eq!(ll1.len(), 4);
+        assert_eq!(ll2.len(), 3);
+        assert_eq!(std::rc::Rc::strong_count(&value), 8);
+        drop(ll1);
+        assert_eq!(std::rc::Rc::strong_count(&value), 4);
+        ll2.clear();
+        assert_eq!(std::rc::Rc::strong_count(&value), 1);
+
+        let ll3: LinkedList<_> = (0..100_000).collect();
+        drop(ll3);
+    }
+
+    #[test]
+    fn test_handles() {
+        let mut ll1 = LinkedList::new();
+        let h2 = ll1.push_back_handle(2);
+        let h1 = ll1.push_front_handle(1);
+        let h4 = ll1.push_back_handle(4);
+        let h3 = ll1.insert_after_handle(&h2, 3).unwrap();
+        let h0 = ll1.insert_before_handle(&h1, 0).unwrap();
+        assert_eq!([0, 1, 2, 3, 4], ll1);
+        assert_eq!(*ll1.get_handle(&h3).unwrap(), 3);
+        assert_eq!(*ll1.get_handle(&ll1.back_handle().unwrap()).unwrap(), 4);
+        *ll1.get_handle_mut(&h0).unwrap() = -1;
+        assert_eq!(*ll1.front().unwrap(), -1);
+        *ll1.get_handle_mut(&h0).unwrap() = 0;
+        assert_eq!(
+            ll1.handle_value(&h2).unwrap() + ll1.handle_value(&h3).unwrap(),
+            5
+        );
+
+        ll1.move_handle_to_front(&h3).unwrap();
+        ll1.move_handle_to_back(&h0).unwrap();
+        assert_eq!([3, 1, 2, 4, 0], ll1);
+        assert_eq!(ll1.remove_handle(&h2).unwrap(), 2);
+        assert_eq!(ll1.remove_handle(&h0).unwrap(), 0);
+        assert_eq!([3, 1, 4], ll1);
+        assert_eq!(ll1.len(), 3);
+        assert_eq!(*ll1.back().unwrap(), 4);
+        ll1.insert_after_handle(&h4, 5).unwrap();
+        assert_eq!(
+            ll1.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 4, 1, 3]
+        );
+
+        ll1.reverse();
+        assert_eq!([5, 4, 1, 3], ll1);
+        assert_eq!(*ll1.get_handle(&h1).unwrap(), 1);
+        assert_eq!(ll1.remove_handle(&h3).unwrap(), 3);
+        assert_eq!([5, 4, 1], ll1);
+    }
+
+    #[test]
+    fn test_invalid_handles() {
+        let mut ll1 = LinkedList::from([1, 2]);
+        let mut ll2 = LinkedList::from([3, 4]);
+        let h1 = ll1.front_handle().unwrap();
+        let h3 = ll2.front_handle().unwrap();
+
+        assert!(matches!(
+            ll1.remove_handle(&h3),
+            Err(LinkedListError::ForeignHandle)
+        ));
+        assert!(matches!(
+            ll2.get_handle(&h1),
+            Err(LinkedListError::ForeignHandle)
+        ));
+        assert!(matches!(
+            ll2.move_handle_to_front(&h1),
+            Err(LinkedListError::ForeignHandle)
+        ));
+        assert!(matches!(
+            ll2.insert_after_handle(&h1, 5),
+            Err(LinkedListError::ForeignHandle)
+        ));
+        assert_eq!([1, 2], ll1);
+        assert_eq!([3, 4], ll2);
+
+        assert_eq!(ll1.pop_front().unwrap(), 1);
+        assert!(matches!(
+            ll1.remove_handle(&h1),
+            Err(LinkedListError::StaleHandle)
+        ));
+        assert!(matches!(
+            ll1.insert_before_handle(&h1, 0),
+            Err(LinkedListError::StaleHandle)
+        ));
+
+        let h2 = ll1.front_handle().unwrap();
+        ll1.append(&mut ll2);
+        assert_eq!(*ll1.get_handle(&h2).unwrap(), 2);
+        assert!(matches!(
+            ll2.get_handle(&h3),
+            Err(LinkedListError::StaleHandle)
+        ));
+        assert!(matches!(
+            ll1.remove_handle(&h3),
+            Err(LinkedListError::ForeignHandle)
+        ));
+        assert_eq!([2, 3, 4], ll1);
+
+        let h2 = ll1.front_handle().unwrap();
+        ll1.clear();
+        ll1.push_back(5);
+        assert!(matches!(
+            ll1.remove_handle(&h2),
+            Err(LinkedListError::StaleHandle)
+        ));
+        drop(ll1);
+        assert!(matches!(
+            ll2.remove_handle(&h2),
+            Err(LinkedListError::ForeignHandle)
+        ));
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        let bump = bumpalo::Bump::with_capacity(1 << 16);
+        let mut ll = LinkedList::new_in(&bump);
+        assert!(std::ptr::eq(*ll.allocator(), &bump));
+
+        let allocations = allocation_counter::measure(|| {
+            for i in 0..100 {
+                ll.push_back(i);
+            }
+            let handle = ll.push_front_handle(-1);
+            ll.reverse();
+            ll.sort();
+            ll.remove_handle(&handle).unwrap();
+            ll.pop(50).unwrap();
+            ll.push(50, 50).unwrap();
+        });
+        assert_eq!(allocations.count_total, 0);
+        assert!(bump.allocated_bytes() > 0);
+
+        assert_eq!(ll.len(), 100);
+        assert_eq!(*ll.front().unwrap(), 0);
+        assert_eq!(*ll.back().unwrap(), 99);
+        assert!(ll.iter().copied().eq(0..100));
+
+        let values: Vec<_> = ll.into_iter().collect();
+        assert_eq!(values[..3], [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_slot_reuse() {
+        let mut ll1 = LinkedList::new();
+        ll1.extend(0..10);
+        ll1.clear();
+
+        let allocations = allocation_counter::measure(|| {
+            for i in 0..100 {
+                ll1.push_back(i);
+                ll1.push_front(i);
+                ll1.pop_back().unwrap();
+                ll1.pop_front().unwrap();
+            }
+            ll1.extend(0..10);
+        });
+        assert_eq!(allocations.count_total, 0);
+        assert!(ll1.iter().copied().eq(0..10));
+    }
+
+    #[test]
+    fn test_std_accessors() {
+        let mut ll1 = LinkedList::<i32>::new();
+        assert!(ll1.first().is_none());
+        assert!(ll1.last().is_none());
+        assert!(ll1.front_mut().is_none());
+        assert!(ll1.back_mut().is_none());
+
+        let mut ll2 = LinkedList::from([2, 3, 5]);
+        assert_eq!(*ll2.first().unwrap(), 2);
+        assert_eq!(*ll2.last().unwrap(), 5);
+        assert_eq!(ll2.get(1), Some(&3));
+        assert_eq!(ll2.get(3), None);
+        *ll2.front_mut().unwrap() = 1;
+        *ll2.back_mut().unwrap() = 7;
+        assert_eq!([1, 3, 7], ll2);
+        assert!(ll2.contains(&3));
+        assert!(!ll2.contains(&5));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        ll1.swap(0, 4);
+        assert_eq!([5, 2, 3, 4, 1], ll1);
+        ll1.swap(3, 1);
+        assert_eq!([5, 4, 3, 2, 1], ll1);
+        ll1.swap(2, 2);
+        assert_eq!([5, 4, 3, 2, 1], ll1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the index is 3")]
+    fn test_swap_out_of_bounds() {
+        let mut ll1 = LinkedList::from([1, 2, 3]);
+        ll1.swap(3, 0);
+    }
+
+    #[test]
+    fn test_truncate_resize() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        ll1.truncate(10);
+        assert_eq!(ll1.len(), 5);
+        ll1.truncate(2);
+        assert_eq!([1, 2], ll1);
+        assert_eq!(*ll1.back().unwrap(), 2);
+        ll1.resize(4, 0);
+        assert_eq!([1, 2, 0, 0], ll1);
+        ll1.truncate(0);
+        assert!(ll1.is_empty());
+        assert!(ll1.front().is_none());
+
+        let mut n = 0;
+        ll1.resize_with(3, || {
+            n += 1;
+            n
+        });
+        assert_eq!([1, 2, 3], ll1);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut ll1 = LinkedList::from([1, 2, 3, 4, 5]);
+        let handle = ll1.front_handle().unwrap();
+        ll1.rotate_left(2);
+        assert_eq!([3, 4, 5, 1, 2], ll1);
+        ll1.rotate_left(4);
+        assert_eq!([2, 3, 4, 5, 1], ll1);
+        ll1.rotate_right(1);
+        assert_eq!([1, 2, 3, 4, 5], ll1);
+        ll1.rotate_right(5);
+        assert_eq!([1, 2, 3, 4, 5], ll1);
+        assert_eq!(*ll1.get_handle(&handle).unwrap(), 1);
+        assert_eq!(*ll1.back().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut ll1 = LinkedList::from([2, 3, 5, 7]);
+        assert_eq!(ll1[0], 2);
+        assert_eq!(ll1[3], 7);
+        ll1[1] = 4;
+        ll1[2] *= 10;
+        assert_eq!([2, 4, 50, 7], ll1);
+        assert_eq!(
+            ll1.set_value(9, 0).unwrap_err().to_string(),
+            "linked list index out of bounds"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 4 but the index is 4")]
+    fn test_index_out_of_bounds() {
+        let ll1 = LinkedList::from([2, 3, 5, 7]);
+        let _ = ll1[4];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1[0] = 1;
+    }
+
+    #[test]
+    fn test_try_push() {
+        #[derive(Debug, PartialEq)]
+        struct Token(u32);
+
+        let mut ll1 = LinkedList::new();
+        assert!(ll1.try_push(0, Token(1)).is_ok());
+        assert!(ll1.try_push(1, Token(3)).is_ok());
+        assert!(ll1.try_push(1, Token(2)).is_ok());
+
+        let (error, token) = ll1.try_push(4, Token(4)).unwrap_err();
+        assert!(matches!(error, LinkedListError::IndexOutOfBounds));
+        assert_eq!(token, Token(4));
+
+        assert!(ll1.try_set_value(0, Token(0)).is_ok());
+        let (_, token) = ll1.try_set_value(3, token).unwrap_err();
+        assert_eq!(token, Token(4));
+        assert_eq!([Token(0), Token(2), Token(3)], ll1);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let ll1 = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(
+            ll1.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        let mut iter = ll1.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1.sort();
+        assert!(ll1.is_empty());
+
+        let mut ll2 = LinkedList::from([5, 2, 7, 3, 2, 0]);
+        let handle = ll2.push_back_handle(4);
+        ll2.sort();
+        assert_eq!([0, 2, 2, 3, 4, 5, 7], ll2);
+        assert_eq!(*ll2.get_handle(&handle).unwrap(), 4);
+        assert_eq!(*ll2.back().unwrap(), 7);
+        assert_eq!(
+            ll2.iter().rev().copied().collect::<Vec<_>>(),
+            vec![7, 5, 4, 3, 2, 2, 0]
+        );
+
+        let mut ll3 = LinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        ll3.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!([(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')], ll3);
+    }
+
+    #[test]
+    fn test_par_sort() {
+        let values: Vec<_> = (0..10_000u64)
+            .map(|i| (i.wrapping_mul(2_654_435_761) % 100, i))
+            .collect();
+        let mut ll1: LinkedList<_> = values.iter().copied().collect();
+        let handle = ll1.push_front_handle((50, 10_000));
+        ll1.par_sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut sorted = values.clone();
+        sorted.insert(0, (50, 10_000));
+        sorted.sort_by_key(|value| value.0);
+        assert_eq!(sorted, ll1);
+        assert_eq!(*ll1.get_handle(&handle).unwrap(), (50, 10_000));
+        assert_eq!(ll1.iter().rev().count(), 10_001);
+
+        let mut ll2 = LinkedList::from([3, 1, 2]);
+        ll2.par_sort();
+        assert_eq!([1, 2, 3], ll2);
+
+        let mut ll3: LinkedList<_> = (0..20).rev().collect();
+        ll3.pop(3).unwrap();
+        ll3.pop_front().unwrap();
+        ll3.push_back(30);
+        ll3.par_sort();
+        assert_eq!(ll3.len(), 19);
+        assert!(ll3.iter().is_sorted());
+        assert_eq!(*ll3.back().unwrap(), 30);
+
+        let mut ll4 = LinkedList::<i32>::new();
+        ll4.par_sort();
+        assert!(ll4.is_empty());
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut ll1 = LinkedList::<i32>::new();
+        ll1.dedup();
+        assert!(ll1.is_empty());
+
+        let mut ll2 = LinkedList::from([1, 1, 2, 3, 3]);
+        let handle = ll2.push_back_handle(3);
+        ll2.push_back(4);
+        ll2.dedup();
+        assert_eq!([1, 2, 3, 4], ll2);
+        assert_eq!(ll2.len(), 4);
+        assert_eq!(
+            ll2.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        assert!(matches!(
+            ll2.get_handle(&handle),
+            Err(LinkedListError::StaleHandle)
+        ));
+
+        let mut ll3 = LinkedList::from([10, 11, 20, 25, 31, 12]);
+        ll3.dedup_by_key(|value| *value / 10);
+        assert_eq!([10, 20, 31, 12], ll3);
+
+        let mut ll4 = LinkedList::from(["a", "A", "b", "B", "b", "c", "C"]);
+        let removed = ll4.dedup_by_drain(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(["a", "b", "c"], ll4);
+        assert_eq!(*ll4.back().unwrap(), "c");
+        assert_eq!(removed, vec!["A", "B", "b", "C"]);
+    }
+}
+
+#[cfg(test)]
+mod unrolled_tests {
+    use super::linkedlist::Result;
+    use super::unrolledlist::*;
+
+    type SmallList<T> = UnrolledList<T, 4>;
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn test_index_out_of_bounds() {
+        let ll1 = SmallList::from([1, 2]);
+        let _ = ll1[2];
+    }
+
+    #[test]
+    fn test_new() {
+        let ll1 = UnrolledList::<i32>::new();
+        assert_eq!(ll1.len(), 0);
+        assert_eq!(ll1.node_count(), 0);
+        assert!(ll1.is_empty());
+        assert!(ll1.get_value(0).is_err());
+    }
+
+    #[test]
+    fn test_len() {
+        let mut ll1 = SmallList::<i32>::from([]);
+        assert_eq!(ll1.len(), 0);
+        ll1.push_front(2);
+        assert_eq!(ll1.len(), 1);
+        ll1.push_back(3);
+        assert_eq!(ll1.len(), 2);
+        ll1.push(1, 5).unwrap();
+        assert_eq!(ll1.len(), 3);
+        ll1.pop(1).unwrap();
+        assert_eq!(ll1.len(), 2);
+        ll1.pop_front().unwrap();
+        assert_eq!(ll1.len(), 1);
+        ll1.pop_back().unwrap();
+        assert_eq!(ll1.len(), 0);
+
+        let mut ll2 = SmallList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
+        assert_eq!(ll2.len(), 9);
+        ll2.reverse();
+        assert_eq!(ll2.len(), 9);
+        ll2.clear();
+        assert_eq!(ll2.len(), 0);
+    }
+
+    #[test]
+    fn test_node_count() {
+        let ll1 = SmallList::from([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(ll1.node_count(), 3);
+
+        let mut ll2 = SmallList::new();
+        for i in 0..4 {
+            ll2.push_back(i);
+        }
+        assert_eq!(ll2.node_count(), 1);
+        ll2.push_back(4);
+        assert_eq!(ll2.node_count(), 2);
+        assert_eq!(ll2, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_value() {
+        let ll1 = SmallList::<i32>::from([]);
+        assert!(ll1.get_value(0).is_err());
+
+        let ll2 = SmallList::from([2, 3, 5, 7, 11, 13]);
+        assert_eq!(*ll2.get_value(0).unwrap(), 2);
+        assert_eq!(*ll2.get_value(3).unwrap(), 7);
+        assert_eq!(*ll2.get_value(4).unwrap(), 11);
+        assert_eq!(*ll2.get_value(5).unwrap(), 13);
+        assert!(ll2.get_value(6).is_err());
+    }
+
+    #[test]
+    fn test_get_value_mut() {
+        let mut ll1 = SmallList::<i32>::from([]);
+        assert!(ll1.get_value_mut(0).is_err());
+
+        let mut ll2 = SmallList::from([2, 3, 5, 7, 11]);
+        *ll2.get_value_mut(0).unwrap() = 1;
+        *ll2.get_value_mut(4).unwrap() = 13;
+        let v: Vec<_> = ll2.into();
+        assert_eq!(v, vec![1, 3, 5, 7, 13]);
+    }
+
+    #[test]
+    fn test_set_value() {
+        let mut ll1 = SmallList::<i32>::from([]);
+        assert!(ll1.set_value(0, 1).is_err());
+
+        let mut ll2 = SmallList::from([2, 3, 5, 7, 11]);
+        ll2.set_value(1, 13).unwrap();
+        ll2.set_value(4, 17).unwrap();
+        assert!(ll2.set_value(5, 19).is_err());
+        assert_eq!(ll2, [2, 13, 5, 7, 17]);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut ll1 = SmallList::new();
+        assert!(ll1.push(1, 0).is_err());
+        ll1.push(0, 3).unwrap();
+        assert_eq!(ll1, [3]);
+        ll1.push(0, 2).unwrap();
+        assert_eq!(ll1, [2, 3]);
+        ll1.push(2, 7).unwrap();
+        assert_eq!(ll1, [2, 3, 7]);
+        ll1.push(2, 5).unwrap();
+        assert_eq!(ll1, [2, 3, 5, 7]);
+        ll1.push(2, 4).unwrap();
+        assert_eq!(ll1, [2, 3, 4, 5, 7]);
+        ll1.push(5, 8).unwrap();
+        assert_eq!(ll1, [2, 3, 4, 5, 7, 8]);
+        assert_eq!(ll1.node_count(), 2);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut ll1 = SmallList::new();
+        for i in (0..10).rev() {
+            ll1.push_front(i);
+        }
+        assert_eq!(ll1, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut ll1 = SmallList::new();
+        for i in 0..10 {
+            ll1.push_back(i);
+        }
+        assert_eq!(ll1, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut ll1 = SmallList::from([2, 3, 5, 7, 11, 13]);
+        assert!(ll1.pop(6).is_err());
+        assert_eq!(ll1.pop(5).unwrap(), 13);
+        assert_eq!(ll1, [2, 3, 5, 7, 11]);
+        assert_eq!(ll1.pop(1).unwrap(), 3);
+        assert_eq!(ll1, [2, 5, 7, 11]);
+        assert_eq!(ll1.pop(0).unwrap(), 2);
+        assert_eq!(ll1, [5, 7, 11]);
+        ll1.pop(0).unwrap();
+        ll1.pop(0).unwrap();
+        ll1.pop(0).unwrap();
+        assert_eq!(ll1, []);
+        assert_eq!(ll1.node_count(), 0);
+        assert!(ll1.pop(0).is_err());
+
+        let mut ll2 = SmallList::from([1, 2, 3, 4, 5, 6]);
+        assert_eq!(ll2.node_count(), 2);
+        ll2.pop(0).unwrap();
+        ll2.pop(0).unwrap();
+        assert_eq!(ll2.node_count(), 2);
+        ll2.pop(0).unwrap();
+        assert_eq!(ll2.node_count(), 1);
+        assert_eq!(ll2, [4, 5, 6]);
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut ll1 = SmallList::from([2, 3, 5, 7, 11]);
+        assert_eq!(ll1.pop_front().unwrap(), 2);
+        assert_eq!(ll1.pop_front().unwrap(), 3);
+        assert_eq!(ll1, [5, 7, 11]);
+        ll1.pop_front().unwrap();
+        ll1.pop_front().unwrap();
+        ll1.pop_front().unwrap();
+        assert!(ll1.pop_front().is_err());
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut ll1 = SmallList::from([2, 3, 5, 7, 11]);
+        assert_eq!(ll1.pop_back().unwrap(), 11);
+        assert_eq!(ll1.pop_back().unwrap(), 7);
+        assert_eq!(ll1, [2, 3, 5]);
+        ll1.pop_back().unwrap();
+        ll1.pop_back().unwrap();
+        ll1.pop_back().unwrap();
+        assert!(ll1.pop_back().is_err());
+    }
+
+    #[test]
+    fn test_split_and_merge() {
+        let mut ll1 = SmallList::new();
+        let mut v = Vec::new();
+
+        for i in 0..200 {
+            let index = (i * 7) % (v.len() + 1);
+            ll1.push(index, i).unwrap();
+            v.insert(index, i);
+        }
+        assert_eq!(ll1, v);
+        assert!(ll1.node_count() >= 50);
+
+        while !v.is_empty() {
+            let index = (v.len() * 5) % v.len().max(1) / 2;
+            assert_eq!(ll1.pop(index).unwrap(), v.remove(index));
+            assert_eq!(ll1.len(), v.len());
+        }
+        assert_eq!(ll1, []);
+        assert_eq!(ll1.node_count(), 0);
+    }
+
+    #[test]
+    fn test_shifted_values() {
+        let mut ll1 = SmallList::new();
+        let mut v = std::collections::VecDeque::new();
+
+        for i in 0..300 {
+            match i % 5 {
+                0 | 3 => {
+                    ll1.push_back(i);
+                    v.push_back(i);
+                }
+                1 => {
+                    assert_eq!(ll1.pop_front().ok(), v.pop_front());
+                }
+                2 => {
+                    ll1.push_front(i);
+                    v.push_front(i);
+                }
+                _ => {
+                    let index = i % (v.len() + 1);
+                    ll1.push(index, i).unwrap();
+                    v.insert(index, i);
+                }
+            }
+            assert!(ll1.iter().eq(v.iter()));
+        }
+
+        let ll2: UnrolledList<_> = (0..1000).collect();
+        let mut ll3 = ll2.clone();
+        let allocations = allocation_counter::measure(|| {
+            assert!(ll2.into_iter().eq(0..1000));
+            while ll3.pop_front().is_ok() {}
+        });
+        assert_eq!(allocations.count_total, 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut ll1 = SmallList::from([2, 3, 5, 7, 11]);
+        ll1.clear();
+        assert_eq!(ll1.len(), 0);
+        assert_eq!(ll1, []);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut ll1 = SmallList::<i32>::new();
+        ll1.reverse();
+        assert_eq!(ll1, []);
+
+        let mut ll2 = SmallList::from([1]);
+        ll2.reverse();
+        assert_eq!(ll2, [1]);
+
+        let mut ll3 = SmallList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
+        ll3.reverse();
+        assert_eq!(ll3, [23, 19, 17, 13, 11, 7, 5, 3, 2]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let ll1 = SmallList::<i32>::new();
+        assert_eq!(ll1.iter().next(), None);
+
+        let ll2 = SmallList::from([2, 3, 5, 7, 11]);
+        let mut ll2_iter = ll2.iter();
+        assert_eq!(ll2_iter.next(), Some(&2));
+        assert_eq!(ll2_iter.next(), Some(&3));
+        assert_eq!(ll2_iter.next(), Some(&5));
+        assert_eq!(ll2_iter.next(), Some(&7));
+        assert_eq!(ll2_iter.next(), Some(&11));
+        assert_eq!(ll2_iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut ll1 = SmallList::<i32>::new();
+        assert_eq!(ll1.iter_mut().next(), None);
+
+        let mut ll2 = SmallList::from([2, 3, 5, 7, 11]);
+        ll2.iter_mut().for_each(|n| *n += 1);
+        assert_eq!(ll2, [3, 4, 6, 8, 12]);
+    }
+
+    #[test]
+    fn test_default() {
+        let ll1 = UnrolledList::<i32>::new();
+        let ll2 = UnrolledList::default();
+        assert_eq!(ll1, ll2);
+        assert_eq!(ll2.len(), 0);
+    }
+
+    #[test]
+    fn test_index() {
+        let ll1 = SmallList::<i32>::new();
+        assert!(std::panic::catch_unwind(|| {
+            let _a = ll1[0];
+        })
+        .is_err());
+
+        let ll2 = SmallList::from([2, 3, 5, 7, 11]);
+        assert_eq!(ll2[0], 2);
+        assert_eq!(ll2[4], 11);
+        assert!(std::panic::catch_unwind(|| {
+            let _a = ll2[5];
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut ll1 = SmallList::from([2, 3, 5, 7, 11]);
+        ll1[0] = 13;
+        ll1[4] = 17;
+        assert_eq!(ll1, [13, 3, 5, 7, 17]);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let ll1 = SmallList::from(vec![2, 3, 5, 7, 11].as_slice());
+        assert_eq!(ll1, [2, 3, 5, 7, 11]);
+
+        let ll2 = SmallList::from(vec![2, 3, 5, 7, 11].as_mut_slice());
+        assert_eq!(ll2, [2, 3, 5, 7, 11]);
+
+        let ll3 = SmallList::from(&[2, 3, 5, 7, 11]);
+        assert_eq!(ll3, [2, 3, 5, 7, 11]);
+
+        let ll4 = SmallList::from(&mut [2, 3, 5, 7, 11]);
+        assert_eq!(ll4, [2, 3, 5, 7, 11]);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let ll1 = SmallList::<i32>::from(vec![]);
+        assert_eq!(ll1, SmallList::new());
+
+        let ll2 = SmallList::from(vec![2, 3, 5, 7, 11]);
+        assert_eq!(ll2, [2, 3, 5, 7, 11]);
+        assert_eq!(ll2.node_count(), 2);
+    }
+
+    #[test]
+    fn test_try_into_array() {
+        let arr: [_; 0] = SmallList::<i32>::from([]).try_into().unwrap();
+        assert_eq!(arr, []);
+
+        let arr: [_; 5] = SmallList::from([2, 3, 5, 7, 11]).try_into().unwrap();
+        assert_eq!(arr, [2, 3, 5, 7, 11]);
+
+        let arr_result: Result<[_; 1]> = SmallList::<i32>::from([]).try_into();
+        assert!(arr_result.is_err());
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let v: Vec<_> = SmallList::<i32>::from([]).into();
+        assert_eq!(v, vec![]);
+
+        let v: Vec<_> = SmallList::from([2, 3, 5, 7, 11]).into();
+        assert_eq!(v, vec![2, 3, 5, 7, 11]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let ll1 = SmallList::from([2, 3, 5, 7, 11]);
+        let mut iter = ll1.into_iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        let v: Vec<_> = iter.collect();
+        assert_eq!(v, vec![3, 5, 7, 11]);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        assert_eq!(SmallList::<i32>::from([]), SmallList::<i32>::from([]));
+        assert_eq!(
+            SmallList::from([2, 3, 5, 7, 11]),
+            SmallList::from([2, 3, 5, 7, 11])
+        );
+        assert_eq!(
+            UnrolledList::<_, 2>::from([2, 3, 5, 7, 11]),
+            [2, 3, 5, 7, 11]
+        );
+        assert_ne!(SmallList::from([2, 3, 5]), SmallList::from([2, 3, 5, 7]));
+        assert_ne!(SmallList::from([2, 3, 5]), vec![2, 3, 6]);
+    }
+}
+
+#[cfg(test)]
+mod skip_tests {
+    use super::linkedlist::Result;
+    use super::skiplist::*;
+
+    #[test]
+    fn test_new() {
+        let sl1 = SkipList::<i32>::new();
+        assert_eq!(sl1.len(), 0);
+        assert!(sl1.is_empty());
+        assert!(sl1.get_value(0).is_err());
+    }
+
+    #[test]
+    fn test_len() {
+        let mut sl1 = SkipList::<i32>::from([]);
+        assert_eq!(sl1.len(), 0);
+        sl1.push_front(2);
+        assert_eq!(sl1.len(), 1);
+        sl1.push_back(3);
+        assert_eq!(sl1.len(), 2);
+        sl1.push(1, 5).unwrap();
+        assert_eq!(sl1.len(), 3);
+        sl1.pop(1).unwrap();
+        assert_eq!(sl1.len(), 2);
+        sl1.pop_front().unwrap();
+        assert_eq!(sl1.len(), 1);
+        sl1.pop_back().unwrap();
+        assert_eq!(sl1.len(), 0);
+
+        let mut sl2 = SkipList::from([2, 3, 5, 7, 11]);
+        assert_eq!(sl2.len(), 5);
+        sl2.reverse();
+        assert_eq!(sl2.len(), 5);
+        sl2.clear();
+        assert_eq!(sl2.len(), 0);
+    }
+
+    #[test]
+    fn test_get_value() {
+        let sl1 = SkipList::<i32>::from([]);
+        assert!(sl1.get_value(0).is_err());
+
+        let sl2 = SkipList::from([2, 3, 5]);
+        assert_eq!(*sl2.get_value(0).unwrap(), 2);
+        assert_eq!(*sl2.get_value(1).unwrap(), 3);
+        assert_eq!(*sl2.get_value(2).unwrap(), 5);
+        assert!(sl2.get_value(3).is_err());
+    }
+
+    #[test]
+    fn test_get_value_mut() {
+        let mut sl1 = SkipList::<i32>::from([]);
+        assert!(sl1.get_value_mut(0).is_err());
+
+        let mut sl2 = SkipList::from([2, 3, 5]);
+        *sl2.get_value_mut(0).unwrap() = 7;
+        *sl2.get_value_mut(2).unwrap() = 13;
+        assert_eq!(sl2, [7, 3, 13]);
+    }
+
+    #[test]
+    fn test_set_value() {
+        let mut sl1 = SkipList::<i32>::from([]);
+        assert!(sl1.set_value(0, 1).is_err());
+
+        let mut sl2 = SkipList::from([2, 3, 5]);
+        sl2.set_value(0, 7).unwrap();
+        sl2.set_value(1, 11).unwrap();
+        sl2.set_value(2, 13).unwrap();
+        assert_eq!(sl2, [7, 11, 13]);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut sl1 = SkipList::new();
+        assert!(sl1.push(1, 0).is_err());
+        sl1.push(0, 3).unwrap();
+        assert_eq!(sl1, [3]);
+        sl1.push(0, 2).unwrap();
+        assert_eq!(sl1, [2, 3]);
+        sl1.push(2, 7).unwrap();
+        assert_eq!(sl1, [2, 3, 7]);
+        sl1.push(2, 5).unwrap();
+        assert_eq!(sl1, [2, 3, 5, 7]);
+        assert!(sl1.push(5, 11).is_err());
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut sl1 = SkipList::new();
+        sl1.push_front(5);
+        sl1.push_front(3);
+        sl1.push_front(2);
+        assert_eq!(sl1, [2, 3, 5]);
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut sl1 = SkipList::new();
+        sl1.push_back(2);
+        sl1.push_back(3);
+        sl1.push_back(5);
+        assert_eq!(sl1, [2, 3, 5]);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut sl1 = SkipList::from([2, 3, 5, 7]);
+        assert!(sl1.pop(4).is_err());
+        assert_eq!(sl1.pop(3).unwrap(), 7);
+        assert_eq!(sl1, [2, 3, 5]);
+        assert_eq!(sl1.pop(1).unwrap(), 3);
+        assert_eq!(sl1, [2, 5]);
+        assert_eq!(sl1.pop(0).unwrap(), 2);
+        assert_eq!(sl1, [5]);
+        assert_eq!(sl1.pop(0).unwrap(), 5);
+        assert_eq!(sl1, []);
+        assert!(sl1.pop(0).is_err());
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut sl1 = SkipList::from([2, 3, 5]);
+        assert_eq!(sl1.pop_front().unwrap(), 2);
+        assert_eq!(sl1.pop_front().unwrap(), 3);
+        assert_eq!(sl1.pop_front().unwrap(), 5);
+        assert!(sl1.pop_front().is_err());
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut sl1 = SkipList::from([2, 3, 5]);
+        assert_eq!(sl1.pop_back().unwrap(), 5);
+        assert_eq!(sl1.pop_back().unwrap(), 3);
+        assert_eq!(sl1.pop_back().unwrap(), 2);
+        assert!(sl1.pop_back().is_err());
+    }
+
+    #[test]
+    fn test_random_access() {
+        let mut sl1 = SkipList::new();
+        let mut v = Vec::new();
+
+        for i in 0..1000 {
+            let index = (i * 7919) % (v.len() + 1);
+            sl1.push(index, i).unwrap();
+            v.insert(index, i);
+        }
+        assert_eq!(sl1, v);
+
+        for i in 0..v.len() {
+            assert_eq!(sl1[i], v[i]);
+        }
+
+        for i in 0..500 {
+            let index = (i * 104_729) % v.len();
+            assert_eq!(sl1.pop(index).unwrap(), v.remove(index));
+        }
+        assert_eq!(sl1, v);
+
+        sl1.iter_mut().for_each(|value| *value += 1);
+        v.iter_mut().for_each(|value| *value += 1);
+        for i in 0..v.len() {
+            assert_eq!(sl1[i], v[i]);
+        }
+
+        for i in 0..250 {
+            let index = (i * 31) % (v.len() + 1);
+            sl1.push(index, i).unwrap();
+            v.insert(index, i);
+        }
+        assert_eq!(sl1, v);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut sl1 = SkipList::from([2, 3, 5, 7]);
+        sl1.clear();
+        assert_eq!(sl1.len(), 0);
+        assert_eq!(sl1, []);
+        sl1.push_back(11);
+        assert_eq!(sl1, [11]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut sl1 = SkipList::<i32>::new();
+        sl1.reverse();
+        assert_eq!(sl1, []);
+
+        let mut sl2 = SkipList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
+        sl2.reverse();
+        assert_eq!(sl2, [23, 19, 17, 13, 11, 7, 5, 3, 2]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let sl1 = SkipList::<i32>::new();
+        assert_eq!(sl1.iter().next(), None);
+
+        let sl2 = SkipList::from([2, 3, 5, 7]);
+        let mut sl2_iter = sl2.iter();
+        assert_eq!(sl2_iter.next(), Some(&2));
+        assert_eq!(sl2_iter.next(), Some(&3));
+        assert_eq!(sl2_iter.next(), Some(&5));
+        assert_eq!(sl2_iter.next(), Some(&7));
+        assert_eq!(sl2_iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut sl1 = SkipList::<i32>::new();
+        assert_eq!(sl1.iter_mut().next(), None);
+
+        let mut sl2 = SkipList::from([2, 3, 5, 7]);
+        sl2.push(0, 1).unwrap();
+        sl2.pop(2).unwrap();
+        sl2.iter_mut().for_each(|n| *n += 1);
+        assert_eq!(sl2, [2, 3, 6, 8]);
+
+        let mut sl3: SkipList<_> = (0..100).rev().collect();
+        sl3.reverse();
+        for i in (0..100).step_by(3) {
+            sl3.pop(i / 3 * 2).unwrap();
+        }
+        let allocations = allocation_counter::measure(|| {
+            let iter = sl3.iter_mut();
+            assert_eq!(iter.size_hint(), (66, Some(66)));
+            iter.for_each(|n| *n *= 2);
+        });
+        assert_eq!(allocations.count_total, 0);
+        assert!(sl3
+            .iter()
+            .copied()
+            .eq((0..100).filter(|n| n % 3 != 0).map(|n| n * 2)));
+        assert_eq!(sl3[65], 196);
+        sl3.push(1, -1).unwrap();
+        assert_eq!(sl3.pop_back().unwrap(), 196);
+        assert!(sl3.iter().take(3).eq([2, -1, 4].iter()));
+    }
+
+    #[test]
+    fn test_default() {
+        let sl1 = SkipList::<i32>::new();
+        let sl2 = SkipList::default();
+        assert_eq!(sl1, sl2);
+        assert_eq!(sl2.len(), 0);
+    }
+
+    #[test]
+    fn test_index() {
+        let sl1 = SkipList::<i32>::new();
+        assert!(std::panic::catch_unwind(|| {
+            let _a = sl1[0];
+        })
+        .is_err());
+
+        let sl2 = SkipList::from([2, 3, 5]);
+        assert_eq!(sl2[0], 2);
+        assert_eq!(sl2[1], 3);
+        assert_eq!(sl2[2], 5);
+        assert!(std::panic::catch_unwind(|| {
+            let _a = sl2[3];
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut sl1 = SkipList::from([2, 3, 5]);
+        sl1[0] = 7;
+        sl1[1] = 11;
+        sl1[2] = 13;
+        assert_eq!(sl1, [7, 11, 13]);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let sl1 = SkipList::from(vec![2, 3, 5, 7].as_slice());
+        assert_eq!(sl1, [2, 3, 5, 7]);
+
+        let sl2 = SkipList::from(vec![2, 3, 5, 7].as_mut_slice());
+        assert_eq!(sl2, [2, 3, 5, 7]);
+
+        let sl3 = SkipList::from(&[2, 3, 5, 7]);
+        assert_eq!(sl3, [2, 3, 5, 7]);
+
+        let sl4 = SkipList::from(&mut [2, 3, 5, 7]);
+        assert_eq!(sl4, [2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let sl1 = SkipList::<i32>::from(vec![]);
+        assert_eq!(sl1, SkipList::new());
+
+        let sl2 = SkipList::from(vec![2, 3, 5, 7]);
+        assert_eq!(sl2, [2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_try_into_array() {
+        let arr: [_; 0] = SkipList::<i32>::from([]).try_into().unwrap();
+        assert_eq!(arr, []);
+
+        let arr: [_; 4] = SkipList::from([2, 3, 5, 7]).try_into().unwrap();
+        assert_eq!(arr, [2, 3, 5, 7]);
+
+        let arr_result: Result<[_; 1]> = SkipList::<i32>::from([]).try_into();
+        assert!(arr_result.is_err());
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let v: Vec<_> = SkipList::<i32>::from([]).into();
+        assert_eq!(v, vec![]);
+
+        let mut sl1 = SkipList::from([2, 3, 5, 7]);
+        sl1.push(1, 11).unwrap();
+        sl1.pop(0).unwrap();
+        let v: Vec<_> = sl1.into();
+        assert_eq!(v, vec![11, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let sl1 = SkipList::from([2, 3, 5, 7]);
+        let v: Vec<_> = sl1.into_iter().collect();
+        assert_eq!(v, vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        assert_eq!(SkipList::<i32>::from([]), SkipList::<i32>::from([]));
+        assert_eq!(SkipList::from([2, 3, 5, 7]), SkipList::from([2, 3, 5, 7]));
+        assert_ne!(SkipList::from([2, 3, 5]), SkipList::from([2, 3, 5, 7]));
+        assert_ne!(SkipList::from([2, 3, 5]), vec![2, 3, 6]);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod sorted_tests {
+    use super::sortedlist::*;
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    #[test]
+    fn test_new() {
+        let sl1 = SortedList::<i32>::new();
+        assert_eq!(sl1.len(), 0);
+        assert!(sl1.is_empty());
+        assert!(sl1.first().is_none());
+        assert!(sl1.last().is_none());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut sl1 = SortedList::new();
+        assert_eq!(sl1.insert(5), 0);
+        assert_eq!(sl1.insert(2), 0);
+        assert_eq!(sl1.insert(7), 2);
+        assert_eq!(sl1.insert(3), 1);
+        assert_eq!(sl1.insert(3), 2);
+        assert_eq!(sl1.len(), 5);
+        assert_eq!(sl1.iter().copied().collect::<Vec<_>>(), vec![2, 3, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_insert_stable() {
+        let mut sl1 = SortedList::with_comparator(|a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0));
+        sl1.insert((2, 'a'));
+        sl1.insert((1, 'b'));
+        sl1.insert((2, 'c'));
+        sl1.insert((1, 'd'));
+        assert_eq!(
+            sl1.iter().copied().collect::<Vec<_>>(),
+            vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+        );
+        assert_eq!(sl1.remove(&(2, 'z')), Some((2, 'a')));
+        assert_eq!(sl1.remove(&(1, 'z')), Some((1, 'b')));
+        assert_eq!(
+            sl1.iter().copied().collect::<Vec<_>>(),
+            vec![(1, 'd'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut sl1 = SortedList::from([2, 3, 3, 5, 7]);
+        assert_eq!(sl1.remove(&3), Some(3));
+        assert_eq!(sl1.remove(&4), None);
+        assert_eq!(sl1.remove(&7), Some(7));
+        assert_eq!(sl1.remove(&1), None);
+        assert_eq!(sl1.iter().copied().collect::<Vec<_>>(), vec![2, 3, 5]);
+        assert_eq!(sl1.len(), 3);
+    }
+
+    #[test]
+    fn test_contains() {
+        let sl1 = SortedList::from([2, 3, 5, 7]);
+        assert!(sl1.contains(&2));
+        assert!(sl1.contains(&7));
+        assert!(!sl1.contains(&1));
+        assert!(!sl1.contains(&4));
+        assert!(!sl1.contains(&8));
+        assert!(!SortedList::new().contains(&0));
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut sl1 = SortedList::from([5, 3, 7, 2]);
+        assert_eq!(*sl1.first().unwrap(), 2);
+        assert_eq!(*sl1.last().unwrap(), 7);
+        assert_eq!(sl1.pop_first(), Some(2));
+        assert_eq!(sl1.pop_last(), Some(7));
+        assert_eq!(*sl1.first().unwrap(), 3);
+        assert_eq!(*sl1.last().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_custom_comparator() {
+        let mut sl1 = SortedList::with_comparator(|a: &i32, b: &i32| b.cmp(a));
+        sl1.extend([2, 7, 3, 5]);
+        assert_eq!(sl1.iter().copied().collect::<Vec<_>>(), vec![7, 5, 3, 2]);
+        assert!(sl1.contains(&3));
+        assert_eq!(sl1.remove(&5), Some(5));
+        assert_eq!(
+            sl1.range((Included(6), Included(3)))
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+        assert_eq!(
+            sl1.range((Included(8), Included(3)))
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![7, 3]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let sl1 = SortedList::from([2, 3, 5, 7, 11, 13]);
+        assert_eq!(
+            sl1.range(..).copied().collect::<Vec<_>>(),
+            vec![2, 3, 5, 7, 11, 13]
+        );
+        assert_eq!(sl1.range(3..11).copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+        assert_eq!(
+            sl1.range(3..=11).copied().collect::<Vec<_>>(),
+            vec![3, 5, 7, 11]
+        );
+        assert_eq!(
+            sl1.range(4..).copied().collect::<Vec<_>>(),
+            vec![5, 7, 11, 13]
+        );
+        assert_eq!(sl1.range(..5).copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(
+            sl1.range(8..10).copied().collect::<Vec<_>>(),
+            Vec::<i32>::new()
+        );
+        assert_eq!(
+            sl1.range((Excluded(3), Unbounded))
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![5, 7, 11, 13]
+        );
+
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(i32);
+        let sl2: SortedList<_> = (0..5).map(Key).collect();
+        assert!(sl2.range(Key(1)..Key(3)).eq([Key(1), Key(2)].iter()));
+
+        let comparisons = std::cell::Cell::new(0);
+        let mut sl3 = SortedList::with_comparator(|a: &i32, b: &i32| {
+            comparisons.set(comparisons.get() + 1);
+            a.cmp(b)
+        });
+        sl3.extend(0..100);
+        comparisons.set(0);
+        assert!(sl3.range(2..4).copied().eq(2..4));
+        assert_eq!(comparisons.get(), 6);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut sl1 = SortedList::from([1, 4, 4, 9]);
+        sl1.merge(SortedList::from([0, 4, 5, 10, 11]));
+        assert_eq!(sl1.len(), 9);
+        assert_eq!(
+            sl1.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 4, 4, 4, 5, 9, 10, 11]
+        );
+
+        let mut sl2 = SortedList::new();
+        sl2.merge(SortedList::from([2, 3]));
+        assert_eq!(sl2.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        sl2.merge(SortedList::new());
+        assert_eq!(sl2.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(*sl2.last().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_merge_stable() {
+        let by_key = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+        let mut sl1 = SortedList::with_comparator(by_key);
+        sl1.extend([(1, 'a'), (2, 'a')]);
+        let mut sl2 = SortedList::with_comparator(by_key);
+        sl2.extend([(1, 'b'), (2, 'b')]);
+        sl1.merge(sl2);
+        assert_eq!(
+            sl1.iter().copied().collect::<Vec<_>>(),
+            vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let sl1: SortedList<_> = vec![5, 2, 7, 3].into_iter().collect();
+        let v: Vec<_> = sl1.into();
+        assert_eq!(v, vec![2, 3, 5, 7]);
+
+        let sl2 = SortedList::from(vec![5, 2, 7, 3]);
+        assert_eq!(sl2.into_iter().rev().collect::<Vec<_>>(), vec![7, 5, 3, 2]);
+
+        let sl3 = SortedList::from([3, 1, 2]);
+        let ll: super::doublylinkedlist::LinkedList<_> = sl3.into();
+        assert_eq!([1, 2, 3], ll);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut sl1 = SortedList::from([2, 3, 5]);
+        sl1.clear();
+        assert!(sl1.is_empty());
+        assert_eq!(sl1.insert(1), 0);
+        assert_eq!(sl1.iter().copied().collect::<Vec<_>>(), vec![1]);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod circular_tests {
+    use super::circularlist::*;
+    use super::doublylinkedlist::LinkedList;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_new() {
+        let cl1 = CircularList::<i32>::new();
+        assert_eq!(cl1.len(), 0);
+        assert!(cl1.is_empty());
+        assert!(cl1.front().is_none());
+        assert!(cl1.back().is_none());
+        assert!(cl1.current().is_none());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut cl1 = CircularList::new();
+        cl1.push_back(3);
+        cl1.push_back(5);
+        cl1.push_front(2);
+        assert_eq!(cl1.len(), 3);
+        assert_eq!(cl1, [2, 3, 5]);
+        assert_eq!(cl1.front(), Some(&2));
+        assert_eq!(cl1.back(), Some(&5));
+        assert_eq!(cl1.current(), Some(&3));
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut cl1 = CircularList::from([2, 3, 5, 7]);
+        assert_eq!(cl1.pop_front(), Some(2));
+        assert_eq!(cl1.pop_back(), Some(7));
+        assert_eq!(cl1, [3, 5]);
+        assert_eq!(cl1.pop_back(), Some(5));
+        assert_eq!(cl1.pop_back(), Some(3));
+        assert_eq!(cl1.pop_back(), None);
+        assert_eq!(cl1.pop_front(), None);
+        assert!(cl1.is_empty());
+        cl1.push_back(11);
+        assert_eq!(cl1, [11]);
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut cl1 = CircularList::from([1, 2, 3, 4, 5]);
+        cl1.rotate_left(2);
+        assert_eq!(cl1, [3, 4, 5, 1, 2]);
+        cl1.rotate_left(4);
+        assert_eq!(cl1, [2, 3, 4, 5, 1]);
+        cl1.rotate_left(5);
+        assert_eq!(cl1, [2, 3, 4, 5, 1]);
+        cl1.rotate_left(11);
+        assert_eq!(cl1, [3, 4, 5, 1, 2]);
+        assert_eq!(*cl1.back().unwrap(), 2);
+
+        let mut cl2 = CircularList::<i32>::new();
+        cl2.rotate_left(3);
+        assert!(cl2.is_empty());
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut cl1 = CircularList::from([1, 2, 3, 4, 5]);
+        cl1.rotate_right(2);
+        assert_eq!(cl1, [4, 5, 1, 2, 3]);
+        cl1.rotate_right(4);
+        assert_eq!(cl1, [5, 1, 2, 3, 4]);
+        cl1.rotate_right(0);
+        assert_eq!(cl1, [5, 1, 2, 3, 4]);
+        cl1.rotate_right(6);
+        assert_eq!(cl1, [4, 5, 1, 2, 3]);
+        assert_eq!(*cl1.front().unwrap(), 4);
+        cl1.push_back(6);
+        assert_eq!(cl1, [4, 5, 1, 2, 3, 6]);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut cl1 = CircularList::from([1, 2, 3]);
+        assert_eq!(*cl1.current().unwrap(), 1);
+        cl1.advance();
+        assert_eq!(*cl1.current().unwrap(), 2);
+        cl1.advance();
+        cl1.advance();
+        assert_eq!(*cl1.current().unwrap(), 1);
+        cl1.retreat();
+        assert_eq!(*cl1.current().unwrap(), 3);
+        *cl1.current_mut().unwrap() = 4;
+        assert_eq!(cl1, [1, 2, 4]);
+        cl1.rotate_left(1);
+        assert_eq!(*cl1.current().unwrap(), 4);
+        cl1.reset_current();
+        assert_eq!(*cl1.current().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_remove_current() {
+        let mut cl1 = CircularList::from([1, 2, 3, 4]);
+        cl1.advance();
+        assert_eq!(cl1.remove_current(), Some(2));
+        assert_eq!(*cl1.current().unwrap(), 3);
+        assert_eq!(cl1, [1, 3, 4]);
+        cl1.advance();
+        assert_eq!(cl1.remove_current(), Some(4));
+        assert_eq!(*cl1.current().unwrap(), 1);
+        assert_eq!(*cl1.back().unwrap(), 3);
+        assert_eq!(cl1.remove_current(), Some(1));
+        assert_eq!(*cl1.front().unwrap(), 3);
+        assert_eq!(cl1.remove_current(), Some(3));
+        assert_eq!(cl1.remove_current(), None);
+        assert!(cl1.is_empty());
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let mut cl1 = CircularList::from(['a', 'b', 'c']);
+        let mut order = Vec::new();
+
+        for _ in 0..7 {
+            order.push(*cl1.current().unwrap());
+            cl1.advance();
+        }
+        assert_eq!(order, vec!['a', 'b', 'c', 'a', 'b', 'c', 'a']);
+    }
+
+    #[test]
+    fn test_iter_cycle() {
+        let cl1 = CircularList::from([1, 2, 3]);
+        assert_eq!(cl1.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(
+            cl1.iter_cycle(3).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3, 1, 2, 3]
+        );
+        assert_eq!(cl1.iter_cycle(0).next(), None);
+        assert_eq!(cl1.iter_cycle(2).len(), 6);
+        assert_eq!(CircularList::<i32>::new().iter_cycle(5).next(), None);
+
+        let mut iter = cl1.iter_cycle(usize::MAX);
+        assert_eq!(iter.len(), usize::MAX);
+        assert_eq!(iter.nth(4), Some(&2));
+        assert_eq!(CircularList::<i32>::new().iter_cycle(usize::MAX).len(), 0);
+    }
+
+    #[test]
+    fn test_pop_after_iter() {
+        let mut cl1 = CircularList::from([1, 2, 3]);
+        let values: Vec<_> = cl1.iter().take(2).copied().collect();
+        assert_eq!(values, vec![1, 2]);
+        assert_eq!(cl1.pop_front(), Some(1));
+        assert_eq!(cl1.remove_current(), Some(2));
+        assert_eq!(cl1, [3]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let value = Rc::new(0);
+        let mut cl1 = CircularList::new();
+        for _ in 0..5 {
+            cl1.push_back(Rc::clone(&value));
+        }
+        cl1.rotate_left(2);
+        cl1.advance();
+        assert_eq!(Rc::strong_count(&value), 6);
+        cl1.remove_current();
+        assert_eq!(Rc::strong_count(&value), 5);
+        drop(cl1);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let mut cl2 = CircularList::from([Rc::clone(&value), Rc::clone(&value)]);
+        cl2.clear();
+        assert!(cl2.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_clone_debug() {
+        let cl1 = CircularList::from([1, 2, 3]);
+        let mut cl2 = cl1.clone();
+        cl2.push_back(4);
+        assert_eq!(cl1, [1, 2, 3]);
+        assert_eq!(cl2, [1, 2, 3, 4]);
+        assert_eq!(format!("{:?}", cl1), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let cl1: CircularList<_> = (1..=3).collect();
+        let ll: LinkedList<_> = cl1.into();
+        assert_eq!([1, 2, 3], ll);
+
+        let cl2 = CircularList::from(ll);
+        let v: Vec<_> = cl2.into();
+        assert_eq!(v, vec![1, 2, 3]);
+
+        let cl3 = CircularList::from(vec![1, 2, 3]);
+        assert_eq!(cl3.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod lru_tests {
+    use super::lrucache::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_new() {
+        let cache = LruCache::<i32, i32>::new(3);
+        assert_eq!(cache.len(), 0);
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 3);
+        assert_eq!(cache.peek_lru(), None);
+    }
+
+    #[test]
+    fn test_put_get() {
+        let mut cache = LruCache::new(2);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.put("a", 10), Some(("a", 1)));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.put("d", 4), Some(("c", 3)));
+        assert_eq!(cache.get("a"), Some(&10));
+        assert_eq!(cache.get("d"), Some(&4));
+
+        *cache.get_mut("a").unwrap() += 1;
+        assert_eq!(cache.peek("a"), Some(&11));
+        assert_eq!(cache.put("e", 5), Some(("d", 4)));
+
+        let mut cache2 = LruCache::new(0);
+        assert_eq!(cache2.put(1, 1), Some((1, 1)));
+        assert!(cache2.is_empty());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "one");
+        cache.put(2, "two");
+        assert_eq!(cache.peek(&1), Some(&"one"));
+        assert_eq!(cache.peek_lru(), Some((&1, &"one")));
+        assert!(cache.contains(&1));
+        assert!(!cache.contains(&3));
+        cache.put(3, "three");
+        assert_eq!(cache.peek(&1), None);
+        assert_eq!(cache.peek_lru(), Some((&2, &"two")));
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut cache = LruCache::new(3);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.put(3, 3);
+        assert_eq!(cache.pop(&2), Some(2));
+        assert_eq!(cache.pop(&2), None);
+        assert_eq!(cache.len(), 2);
+        cache.get(&1);
+        assert_eq!(cache.pop_lru(), Some((3, 3)));
+        assert_eq!(cache.pop_lru(), Some((1, 1)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut cache = LruCache::new(4);
+        for i in 0..4 {
+            cache.put(i, i * 10);
+        }
+        cache.get(&0);
+        cache.resize(2);
+        assert_eq!(cache.capacity(), 2);
+        assert_eq!(cache.iter().collect::<Vec<_>>(), vec![(&0, &0), (&3, &30)]);
+        cache.resize(3);
+        cache.put(4, 40);
+        assert_eq!(cache.len(), 3);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut cache = LruCache::new(3);
+        cache.put('a', 1);
+        cache.put('b', 2);
+        cache.put('c', 3);
+        cache.get(&'a');
+        cache.peek(&'b');
+        let entries: Vec<_> = (&cache).into_iter().collect();
+        assert_eq!(entries, vec![(&'a', &1), (&'c', &3), (&'b', &2)]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let value = Rc::new(0);
+        let mut cache = LruCache::new(3);
+        for i in 0..5 {
+            cache.put(i, Rc::clone(&value));
+            cache.get(&(i / 2));
+        }
+        assert_eq!(Rc::strong_count(&value), 4);
+        cache.pop(&4);
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(cache);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod lfu_tests {
+    use super::lfucache::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_new() {
+        let cache = LfuCache::<i32, i32>::new(3);
+        assert_eq!(cache.len(), 0);
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 3);
+        assert_eq!(cache.peek_lfu(), None);
+    }
+
+    #[test]
+    fn test_put_get() {
+        let mut cache = LfuCache::new(2);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.put("c", 30), Some(("c", 3)));
+        assert_eq!(cache.frequency("a"), Some(2));
+        assert_eq!(cache.frequency("c"), Some(2));
+        *cache.get_mut("c").unwrap() += 1;
+        assert_eq!(cache.put("d", 4), Some(("a", 1)));
+        assert_eq!(cache.peek("c"), Some(&31));
+        assert_eq!(cache.put("e", 5), Some(("d", 4)));
+
+        let mut cache2 = LfuCache::new(0);
+        assert_eq!(cache2.put(1, 1), Some((1, 1)));
+        assert!(cache2.is_empty());
+    }
+
+    #[test]
+    fn test_eviction_order() {
+        let mut cache = LfuCache::new(4);
+        for i in 0..4 {
+            cache.put(i, i);
+        }
+        for _ in 0..3 {
+            cache.get(&0);
+        }
+        cache.get(&2);
+        cache.get(&1);
+        cache.get(&3);
+        cache.get(&3);
+        assert_eq!(cache.frequency(&0), Some(4));
+        assert_eq!(cache.frequency(&1), Some(2));
+        assert_eq!(cache.frequency(&2), Some(2));
+        assert_eq!(cache.frequency(&3), Some(3));
+        assert_eq!(cache.peek_lfu(), Some((&2, &2)));
+        assert_eq!(cache.pop_lfu(), Some((2, 2)));
+        assert_eq!(cache.pop_lfu(), Some((1, 1)));
+        assert_eq!(cache.pop_lfu(), Some((3, 3)));
+        assert_eq!(cache.pop_lfu(), Some((0, 0)));
+        assert_eq!(cache.pop_lfu(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut cache = LfuCache::new(4);
+        assert_eq!(cache.iter().next(), None);
+        for i in 0..4 {
+            cache.put(i, i * 10);
+        }
+        cache.get(&2);
+        cache.get(&1);
+        cache.get(&1);
+        let mut iter = cache.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some((&1, &10)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![(&2, &20), (&3, &30), (&0, &0)]
+        );
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3, 0]);
+        assert_eq!(
+            cache.values().copied().collect::<Vec<_>>(),
+            vec![10, 20, 30, 0]
+        );
+        assert_eq!((&cache).into_iter().last(), cache.peek_lfu());
+    }
+
+    #[test]
+    fn test_new_entries_evicted_first() {
+        let mut cache = LfuCache::new(2);
+        cache.put(1, 1);
+        cache.get(&1);
+        cache.put(2, 2);
+        assert_eq!(cache.put(3, 3), Some((2, 2)));
+        assert_eq!(cache.put(4, 4), Some((3, 3)));
+        assert!(cache.contains(&1));
+        assert!(!cache.contains(&3));
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut cache = LfuCache::new(3);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.put(3, 3);
+        cache.get(&2);
+        assert_eq!(cache.pop(&2), Some(2));
+        assert_eq!(cache.pop(&2), None);
+        assert_eq!(cache.frequency(&2), None);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.pop_lfu(), Some((1, 1)));
+        cache.put(4, 4);
+        cache.get(&4);
+        assert_eq!(cache.pop_lfu(), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut cache = LfuCache::new(4);
+        for i in 0..4 {
+            cache.put(i, i * 10);
+            for _ in 0..i {
+                cache.get(&i);
+            }
+        }
+        cache.resize(2);
+        assert_eq!(cache.capacity(), 2);
+        assert!(cache.contains(&2));
+        assert!(cache.contains(&3));
+        cache.resize(3);
+        cache.put(4, 40);
+        assert_eq!(cache.len(), 3);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.peek_lfu(), None);
+    }
+
+    #[test]
+    fn test_drop() {
+        let value = Rc::new(0);
+        let mut cache = LfuCache::new(3);
+        for i in 0..5 {
+            cache.put(i, Rc::clone(&value));
+            cache.get(&(i / 2));
+        }
+        assert_eq!(Rc::strong_count(&value), 4);
+        cache.pop(&4);
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(cache);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod linked_hash_tests {
+    use super::linkedhashmap::*;
+    use super::linkedhashset::*;
+
+    #[test]
+    fn test_map_insert_remove() {
+        let mut map = LinkedHashMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert("b", 2), None);
+        assert_eq!(map.insert("a", 1), None);
+        assert_eq!(map.insert("c", 3), None);
+        assert_eq!(map.insert("b", 20), Some(2));
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"a", &"c"]);
+        assert_eq!(map.remove("a"), Some(1));
+        assert_eq!(map.remove("a"), None);
+        assert_eq!(map.remove_entry("c"), Some(("c", 3)));
+        assert_eq!(map.get("b"), Some(&20));
+        *map.get_mut("b").unwrap() += 1;
+        assert_eq!(map.get("b"), Some(&21));
+        assert!(map.contains_key("b"));
+        assert!(!map.contains_key("c"));
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.front(), None);
+    }
+
+    #[test]
+    fn test_map_move() {
+        let mut map = LinkedHashMap::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+        assert!(map.move_to_front(&3));
+        assert!(map.move_to_back(&1));
+        assert!(!map.move_to_back(&5));
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![3, 2, 4, 1]);
+        assert!(map.move_to_front(&3));
+        assert!(map.move_to_back(&1));
+        assert_eq!(map.front(), Some((&3, &'c')));
+        assert_eq!(map.back(), Some((&1, &'a')));
+        assert_eq!(map.pop_front(), Some((3, 'c')));
+        assert_eq!(map.pop_back(), Some((1, 'a')));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.pop_back(), Some((4, 'd')));
+        assert_eq!(map.pop_back(), Some((2, 'b')));
+        assert_eq!(map.pop_front(), None);
+    }
+
+    #[test]
+    fn test_map_iter() {
+        let map: LinkedHashMap<_, _> = (0..5).map(|i| (i, i * i)).collect();
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(&0, &0), (&1, &1), (&2, &4), (&3, &9), (&4, &16)]
+        );
+        assert_eq!(
+            map.values().rev().copied().collect::<Vec<_>>(),
+            vec![16, 9, 4, 1, 0]
+        );
+        let mut keys = map.keys();
+        assert_eq!(keys.len(), 5);
+        assert_eq!(keys.next_back(), Some(&4));
+        assert_eq!(keys.next(), Some(&0));
+        assert_eq!(keys.len(), 3);
+        assert_eq!((&map).into_iter().count(), 5);
+        assert_eq!(
+            map.clone().into_iter().rev().collect::<Vec<_>>(),
+            vec![(4, 16), (3, 9), (2, 4), (1, 1), (0, 0)]
+        );
+        assert_eq!(format!("{:?}", map), "{0: 0, 1: 1, 2: 4, 3: 9, 4: 16}");
+    }
+
+    #[test]
+    fn test_map_eq() {
+        let map1 = LinkedHashMap::from([(1, 1), (2, 2)]);
+        let mut map2 = LinkedHashMap::from([(2, 2), (1, 1)]);
+        assert_ne!(map1, map2);
+        map2.move_to_back(&2);
+        assert_eq!(map1, map2);
+        assert_eq!(map1, map1.clone());
+        assert_eq!(LinkedHashMap::<i32, i32>::default(), LinkedHashMap::new());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut set = LinkedHashSet::new();
+        assert!(set.insert(3));
+        assert!(set.insert(1));
+        assert!(set.insert(2));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+        assert!(set.contains(&1));
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(set.take(&2), Some(2));
+        assert_eq!(set.take(&2), None);
+        set.extend([4, 5, 6]);
+        assert!(set.move_to_back(&3));
+        assert!(set.move_to_front(&6));
+        assert_eq!(set.front(), Some(&6));
+        assert_eq!(set.back(), Some(&3));
+        assert_eq!(
+            set.iter().rev().copied().collect::<Vec<_>>(),
+            vec![3, 5, 4, 6]
+        );
+        assert_eq!(format!("{:?}", set), "{6, 4, 5, 3}");
+        assert_eq!(set.pop_front(), Some(6));
+        assert_eq!(set.pop_back(), Some(3));
+        assert_eq!(set, LinkedHashSet::from([4, 5]));
+        assert_eq!(set.clone().into_iter().collect::<Vec<_>>(), vec![4, 5]);
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!((&set).into_iter().next(), None);
+    }
+}
+
+#[cfg(test)]
+mod static_tests {
+    use super::linkedlist::LinkedListError;
+    use super::staticlist::*;
+
+    #[test]
+    fn test_new() {
+        let ll1 = StaticLinkedList::<i32, 4>::new();
+        assert_eq!(ll1.len(), 0);
+        assert!(ll1.is_empty());
+        assert_eq!(ll1.capacity(), 4);
+        assert!(!ll1.is_full());
+        assert!(ll1.get_value(0).is_err());
+        assert!(StaticLinkedList::<i32, 0>::new().is_full());
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut ll1 = StaticLinkedList::<i32, 5>::new();
+        ll1.push_back(3).unwrap();
+        ll1.push_front(1).unwrap();
+        ll1.push(1, 2).unwrap();
+        ll1.push(3, 5).unwrap();
+        ll1.push(3, 4).unwrap();
+        assert_eq!(ll1, [1, 2, 3, 4, 5]);
+        assert!(ll1.is_full());
+        assert!(matches!(
+            ll1.push_back(6),
+            Err((LinkedListError::CapacityExceeded { capacity: 5 }, 6))
+        ));
+        assert!(matches!(
+            ll1.push(7, 6),
+            Err((LinkedListError::IndexOutOfBounds { index: 7, size: 5 }, 6))
+        ));
+        assert!(matches!(ll1.push(2, 7), Err((_, 7))));
+        assert!(matches!(ll1.push_front(8), Err((_, 8))));
+
+        assert_eq!(ll1.pop(2).unwrap(), 3);
+        assert_eq!(ll1.pop_back().unwrap(), 5);
+        assert_eq!(ll1.pop_front().unwrap(), 1);
+        assert!(ll1.pop(2).is_err());
+        assert_eq!(ll1, [2, 4]);
+        ll1.push_back(6).unwrap();
+        ll1.push_front(0).unwrap();
+        ll1.push(2, 3).unwrap();
+        assert_eq!(ll1, [0, 2, 3, 4, 6]);
+        assert!(ll1.push_front(-1).is_err());
+
+        ll1.clear();
+        assert!(ll1.is_empty());
+        assert!(ll1.pop_front().is_err());
+        assert!(ll1.pop_back().is_err());
+        for i in 0..5 {
+            ll1.push_back(i).unwrap();
+        }
+        assert_eq!(ll1, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut ll1 = StaticLinkedList::from([2, 3, 5, 7]);
+        assert_eq!(*ll1.get_value(2).unwrap(), 5);
+        *ll1.get_value_mut(1).unwrap() = 4;
+        ll1.set_value(3, 8).unwrap();
+        assert!(ll1.set_value(4, 0).is_err());
+        assert_eq!(ll1[1], 4);
+        ll1[0] = 1;
+        assert_eq!(ll1, vec![1, 4, 5, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let ll1 = StaticLinkedList::from([1, 2]);
+        let _ = ll1[2];
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut ll1 = StaticLinkedList::<i32, 6>::try_from(&[1, 2, 3, 4][..]).unwrap();
+        ll1.pop(1).unwrap();
+        ll1.push_front(0).unwrap();
+        ll1.reverse();
+        assert_eq!(ll1, [4, 3, 1, 0]);
+        ll1.push_back(-1).unwrap();
+        assert_eq!(ll1, [4, 3, 1, 0, -1]);
+        assert_eq!(ll1.pop_back().unwrap(), -1);
+        assert_eq!(ll1.pop_back().unwrap(), 0);
+
+        let mut ll2 = StaticLinkedList::<i32, 2>::new();
+        ll2.reverse();
+        assert!(ll2.is_empty());
+        assert!(StaticLinkedList::<i32, 2>::try_from(&[1, 2, 3][..]).is_err());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut ll1 = StaticLinkedList::<i32, 8>::new();
+        for i in [3, 1, 4] {
+            ll1.push_front(i).unwrap();
+        }
+        ll1.pop(1).unwrap();
+        ll1.push_back(9).unwrap();
+        assert_eq!(ll1.iter().copied().collect::<Vec<_>>(), vec![4, 3, 9]);
+
+        for value in ll1.iter_mut() {
+            *value *= 2;
+        }
+        assert_eq!(ll1, [8, 6, 18]);
+        assert_eq!((&ll1).into_iter().count(), 3);
+        assert_eq!(ll1.clone().into_iter().collect::<Vec<_>>(), vec![8, 6, 18]);
+        assert_eq!(format!("{:?}", ll1), "[8, 6, 18]");
+        assert_eq!(ll1, ll1.clone());
+        assert_ne!(StaticLinkedList::<i32, 8>::default(), ll1);
+        assert_eq!(StaticLinkedList::from([8, 6, 18]), [8, 6, 18]);
+    }
+
+    #[test]
+    fn test_iter_mut_scattered() {
+        let mut ll1 = StaticLinkedList::<i32, 6>::from([0, 1, 2, 3, 4, 5]);
+        ll1.pop(4).unwrap();
+        ll1.pop_front().unwrap();
+        ll1.push(2, 6).unwrap();
+        ll1.reverse();
+        assert_eq!(ll1, [5, 3, 6, 2, 1]);
+
+        let mut iter = ll1.iter_mut();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        *iter.next().unwrap() = 50;
+        assert_eq!(iter.count(), 4);
+        assert_eq!(ll1, [50, 3, 6, 2, 1]);
+
+        ll1.push(1, 7).unwrap();
+        assert!(ll1.push_back(8).is_err());
+        assert_eq!(ll1.pop_back().unwrap(), 1);
+        ll1.push_back(9).unwrap();
+        ll1.iter_mut().for_each(|value| *value += 1);
+        assert_eq!(ll1, [51, 8, 4, 7, 3, 10]);
+        ll1.clear();
+        assert_eq!(ll1.iter_mut().count(), 0);
+        ll1.push_front(1).unwrap();
+        assert_eq!(ll1, [1]);
+    }
+}
+
+#[cfg(test)]
+mod pooled_tests {
+    use super::pooledlist::*;
+
+    #[test]
+    fn test_new() {
+        let ll1 = PooledLinkedList::<i32>::new();
+        assert_eq!(ll1.len(), 0);
+        assert!(ll1.is_empty());
+        assert_eq!(ll1.front(), None);
+        assert!(ll1.get_value(0).is_err());
+        assert!(PooledLinkedList::<i32>::with_capacity(10).capacity() >= 10);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut ll1 = PooledLinkedList::new();
+        ll1.push_back(3);
+        ll1.push_front(1);
+        ll1.push(1, 2).unwrap();
+        ll1.push(3, 5).unwrap();
+        ll1.push(3, 4).unwrap();
+        assert!(ll1.push(6, 6).is_err());
+        assert_eq!(ll1, [1, 2, 3, 4, 5]);
+        assert_eq!(ll1.front(), Some(&1));
+        assert_eq!(ll1.back(), Some(&5));
+
+        assert_eq!(ll1.pop(2).unwrap(), 3);
+        assert_eq!(ll1.pop_back().unwrap(), 5);
+        assert_eq!(ll1.pop_front().unwrap(), 1);
+        assert!(ll1.pop(2).is_err());
+        assert_eq!(ll1, [2, 4]);
+        ll1.push_front(0);
+        ll1.push(2, 3).unwrap();
+        assert_eq!(ll1, [0, 2, 3, 4]);
+        assert_eq!(ll1.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &0]);
+
+        ll1.clear();
+        assert!(ll1.is_empty());
+        assert!(ll1.pop_front().is_err());
+        assert!(ll1.pop_back().is_err());
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut ll1 = PooledLinkedList::from([2, 3, 5, 7, 11]);
+        assert_eq!(*ll1.get_value(1).unwrap(), 3);
+        assert_eq!(*ll1.get_value(4).unwrap(), 11);
+        *ll1.get_value_mut(3).unwrap() = 8;
+        ll1.set_value(0, 1).unwrap();
+        assert!(ll1.set_value(5, 0).is_err());
+        ll1[2] = 4;
+        *ll1.front_mut().unwrap() += 1;
+        *ll1.back_mut().unwrap() += 1;
+        assert_eq!(ll1[1], 3);
+        assert_eq!(ll1, vec![2, 3, 4, 8, 12]);
+    }
+
+    #[test]
+    fn test_reverse_iter() {
+        let mut ll1: PooledLinkedList<_> = (1..=5).collect();
+        ll1.pop(1).unwrap();
+        ll1.reverse();
+        assert_eq!(ll1, [5, 4, 3, 1]);
+        ll1.push_back(0);
+        for value in ll1.iter_mut().rev().take(2) {
+            *value *= 10;
+        }
+        assert_eq!(ll1, [5, 4, 3, 10, 0]);
+        assert_eq!(ll1.iter().len(), 5);
+        assert_eq!((&ll1).into_iter().count(), 5);
+        assert_eq!(format!("{:?}", ll1), "[5, 4, 3, 10, 0]");
+        assert_eq!(
+            ll1.clone().into_iter().rev().collect::<Vec<_>>(),
+            vec![0, 10, 3, 4, 5]
+        );
+        let v: Vec<_> = ll1.into();
+        assert_eq!(v, vec![5, 4, 3, 10, 0]);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut ll1 = PooledLinkedList::with_capacity(4);
+        ll1.extend([1, 2, 3, 4]);
+        assert_eq!(ll1.capacity(), 4);
+        ll1.pop_front().unwrap();
+        ll1.pop_back().unwrap();
+        ll1.push_back(5);
+        ll1.push_front(0);
+        assert_eq!(ll1.capacity(), 4);
+        ll1.reserve(3);
+        assert!(ll1.capacity() >= 7);
+
+        for _ in 0..3 {
+            ll1.pop_front().unwrap();
+        }
+        ll1.shrink_to_fit();
+        assert_eq!(ll1, [5]);
+        assert_eq!(ll1.capacity(), 1);
+        ll1.push_front(4);
+        assert_eq!(ll1, [4, 5]);
+
+        ll1.clear();
+        ll1.shrink_to_fit();
+        assert_eq!(ll1.capacity(), 0);
+        assert_eq!(ll1, []);
+    }
+
+    #[test]
+    fn test_steady_state_allocations() {
+        let mut queue = PooledLinkedList::with_capacity(8);
+        let info = allocation_counter::measure(|| {
+            for i in 0..10_000 {
+                queue.push_back(i);
+                if queue.len() == 8 {
+                    queue.pop_front().unwrap();
+                }
+            }
+        });
+        assert_eq!(info.count_total, 0);
+        assert_eq!(queue.len(), 7);
+
+        let mut queue = PooledLinkedList::new();
+        for i in 0..8 {
+            queue.push_back(i);
+        }
+        queue.clear();
+        let info = allocation_counter::measure(|| {
+            for i in 0..10_000 {
+                queue.push_back(i);
+                queue.push_back(i);
+                queue.pop_front().unwrap();
+                queue.pop_front().unwrap();
+            }
+        });
+        assert_eq!(info.count_total, 0);
+    }
+
+    #[test]
+    fn test_iter_mut_allocations() {
+        let mut ll1 = PooledLinkedList::with_capacity(16);
+        for i in 0..6 {
+            ll1.push_front(i);
+            ll1.push_back(i + 10);
+        }
+        ll1.pop(3).unwrap();
+        ll1.pop_front().unwrap();
+        ll1.reverse();
+        assert_eq!(ll1, [15, 14, 13, 12, 11, 10, 0, 1, 3, 4]);
+
+        let info = allocation_counter::measure(|| {
+            let mut iter = ll1.iter_mut();
+            assert_eq!(iter.len(), 10);
+            *iter.next_back().unwrap() = 40;
+            for value in iter {
+                *value += 1;
+            }
+        });
+        assert_eq!(info.count_total, 0);
+        assert_eq!(ll1, [16, 15, 14, 13, 12, 11, 1, 2, 4, 40]);
+
+        ll1.pop(4).unwrap();
+        ll1.push_front(-1);
+        ll1.push(2, -2).unwrap();
+        assert_eq!(ll1.pop_back().unwrap(), 40);
+        assert_eq!(ll1, [-1, 16, -2, 15, 14, 13, 11, 1, 2, 4]);
+        assert!(ll1
+            .iter()
+            .rev()
+            .eq([4, 2, 1, 11, 13, 14, 15, -2, 16, -1].iter()));
+        assert_eq!(ll1.capacity(), 16);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod bounded_tests {
+    use super::boundedlist::*;
+    use super::doublylinkedlist::LinkedListError;
+
+    #[test]
+    fn test_new() {
+        let bl1 = BoundedList::<i32>::new(3, OverflowPolicy::Reject);
+        assert_eq!(bl1.len(), 0);
+        assert!(bl1.is_empty());
+        assert!(!bl1.is_full());
+        assert_eq!(bl1.max_len(), 3);
+        assert_eq!(bl1.policy(), OverflowPolicy::Reject);
+        assert!(bl1.oldest().is_none());
+        assert!(bl1.newest().is_none());
+    }
+
+    #[test]
+    fn test_reject() {
+        let mut bl1 = BoundedList::new(3, OverflowPolicy::Reject);
+        assert_eq!(bl1.push(1).unwrap(), None);
+        assert_eq!(bl1.push(2).unwrap(), None);
+        assert_eq!(bl1.push(3).unwrap(), None);
+        assert!(bl1.is_full());
+        assert!(matches!(
+            bl1.push(4),
+            Err((LinkedListError::CapacityExceeded, 4))
+        ));
+        assert_eq!(bl1, [1, 2, 3]);
+
+        let mut bl2 = BoundedList::new(0, OverflowPolicy::Reject);
+        assert!(matches!(bl2.push(1), Err((_, 1))));
+        assert!(bl2.is_empty());
+
+        let mut bl3 = BoundedList::new(1, OverflowPolicy::Reject);
+        bl3.push(String::from("kept")).unwrap();
+        let (_, value) = bl3.push(String::from("rejected")).unwrap_err();
+        assert_eq!(value, "rejected");
+        assert_eq!(bl3.oldest().map(String::as_str), Some("kept"));
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let mut bl1 = BoundedList::new(3, OverflowPolicy::DropOldest);
+        for i in 1..=3 {
+            assert_eq!(bl1.push(i).unwrap(), None);
+        }
+        assert_eq!(bl1.push(4).unwrap(), Some(1));
+        assert_eq!(bl1.push(5).unwrap(), Some(2));
+        assert_eq!(bl1, [3, 4, 5]);
+        assert_eq!(*bl1.oldest().unwrap(), 3);
+        assert_eq!(*bl1.newest().unwrap(), 5);
+
+        let mut bl2 = BoundedList::new(0, OverflowPolicy::DropOldest);
+        assert_eq!(bl2.push(1).unwrap(), Some(1));
+        assert!(bl2.is_empty());
+    }
+
+    #[test]
+    fn test_drop_newest() {
+        let mut bl1 = BoundedList::new(3, OverflowPolicy::DropNewest);
+        for i in 1..=3 {
+            assert_eq!(bl1.push(i).unwrap(), None);
+        }
+        assert_eq!(bl1.push(4).unwrap(), Some(3));
+        assert_eq!(bl1.push(5).unwrap(), Some(4));
+        assert_eq!(bl1, [1, 2, 5]);
+
+        let mut bl2 = BoundedList::new(0, OverflowPolicy::DropNewest);
+        assert_eq!(bl2.push(1).unwrap(), Some(1));
+        assert!(bl2.is_empty());
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut bl1 = BoundedList::new(4, OverflowPolicy::Reject);
+        for i in 1..=4 {
+            bl1.push(i).unwrap();
+        }
+        assert_eq!(bl1.pop_oldest(), Some(1));
+        assert_eq!(bl1.pop_newest(), Some(4));
+        assert_eq!(bl1, [2, 3]);
+        assert!(!bl1.is_full());
+
+        bl1.clear();
+        assert!(bl1.is_empty());
+        assert_eq!(bl1.pop_oldest(), None);
+        assert_eq!(bl1.pop_newest(), None);
+    }
+
+    #[test]
+    fn test_set_max_len() {
+        let mut bl1 = BoundedList::new(5, OverflowPolicy::DropOldest);
+        for i in 1..=5 {
+            bl1.push(i).unwrap();
+        }
+        assert_eq!(bl1.set_max_len(3), vec![1, 2]);
+        assert_eq!(bl1, [3, 4, 5]);
+        assert_eq!(bl1.max_len(), 3);
+
+        bl1.set_policy(OverflowPolicy::DropNewest);
+        assert_eq!(bl1.set_max_len(1), vec![5, 4]);
+        assert_eq!(bl1, [3]);
+
+        assert!(bl1.set_max_len(10).is_empty());
+        assert!(!bl1.is_full());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut bl1 = BoundedList::new(3, OverflowPolicy::DropOldest);
+        for i in 1..=5 {
+            bl1.push(i).unwrap();
+        }
+        assert_eq!(bl1.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(
+            bl1.iter_newest().copied().collect::<Vec<_>>(),
+            vec![5, 4, 3]
+        );
+        assert_eq!(bl1.iter_newest().len(), 3);
+
+        let bl2 = bl1.clone();
+        assert_eq!(bl1.into_iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(bl2, vec![3, 4, 5]);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod channel_tests {
+    use super::channel::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_send_recv() {
+        let (tx, rx) = channel();
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!(rx.recv(), Ok(1));
+        assert_eq!(rx.try_recv(), Ok(2));
+        assert_eq!(rx.try_recv(), Err(RecvError::Empty));
+    }
+
+    #[test]
+    fn test_threads() {
+        let (tx, rx) = channel();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for j in 0..100 {
+                        tx.send(i * 100 + j).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut values: Vec<_> = rx.iter().collect();
+        values.sort();
+        assert_eq!(values, (0..400).collect::<Vec<_>>());
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_disconnect() {
+        let (tx, rx) = channel();
+        let tx2 = tx.clone();
+        tx.send(1).unwrap();
+        drop(tx);
+        assert_eq!(rx.try_recv(), Ok(1));
+        assert_eq!(rx.try_recv(), Err(RecvError::Empty));
+        drop(tx2);
+        assert_eq!(rx.try_recv(), Err(RecvError::Disconnected));
+        assert_eq!(rx.recv(), Err(RecvError::Disconnected));
+
+        let (tx, rx) = channel();
+        drop(rx);
+        assert!(matches!(tx.send(5), Err(SendError(5))));
+        assert!(matches!(tx.try_send(6), Err(TrySendError::Disconnected(6))));
+    }
+
+    #[test]
+    fn test_recv_timeout() {
+        let (tx, rx) = channel();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(10)),
+            Err(RecvError::Timeout)
+        );
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            tx.send(7).unwrap();
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(10)), Ok(7));
+        handle.join().unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(10)),
+            Err(RecvError::Disconnected)
+        );
+
+        let (tx, rx) = channel();
+        tx.send(1).unwrap();
+        assert_eq!(rx.recv_timeout(Duration::MAX), Ok(1));
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            tx.send(2).unwrap();
+        });
+        assert_eq!(rx.recv_timeout(Duration::MAX), Ok(2));
+        handle.join().unwrap();
+        assert_eq!(rx.recv_timeout(Duration::MAX), Err(RecvError::Disconnected));
+    }
+
+    #[test]
+    fn test_bounded() {
+        let (tx, rx) = sync_channel(2);
+        tx.send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert!(matches!(tx.try_send(3), Err(TrySendError::Full(3))));
+
+        let handle = thread::spawn(move || {
+            for i in 3..=10 {
+                tx.send(i).unwrap();
+            }
+        });
+        assert_eq!(
+            rx.into_iter().collect::<Vec<_>>(),
+            (1..=10).collect::<Vec<_>>()
+        );
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_bounded_receiver_dropped() {
+        let (tx, rx) = sync_channel(1);
+        tx.send(1).unwrap();
+
+        let handle = thread::spawn(move || tx.send(2));
+        thread::sleep(Duration::from_millis(10));
+        drop(rx);
+        assert!(matches!(handle.join().unwrap(), Err(SendError(2))));
+    }
+
+    #[test]
+    fn test_try_iter() {
+        let (tx, rx) = channel();
+        for i in 0..3 {
+            tx.send(i).unwrap();
+        }
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(rx.try_iter().next(), None);
+    }
+}
+
+#[cfg(all(test, feature = "futures"))]
+mod async_queue_tests {
+    use super::asyncqueue::*;
+    use futures::executor::{block_on, LocalPool};
+    use futures::task::{noop_waker_ref, LocalSpawnExt};
+    use futures::{stream, Sink, SinkExt, StreamExt};
+    use std::cell::RefCell;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn test_send_recv() {
+        let (mut tx, mut rx) = unbounded();
+        block_on(async {
+            tx.send(1).await.unwrap();
+            tx.send(2).await.unwrap();
+            assert_eq!(rx.next().await, Some(1));
+            assert_eq!(rx.next().await, Some(2));
+            drop(tx);
+            assert_eq!(rx.next().await, None);
+        });
+    }
+
+    #[test]
+    fn test_forward() {
+        let (tx, rx) = unbounded();
+        block_on(async {
+            stream::iter(0..10).map(Ok).forward(tx).await.unwrap();
+            assert_eq!(rx.collect::<Vec<_>>().await, (0..10).collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn test_multiple_senders() {
+        let (tx1, rx) = unbounded();
+        let mut tx2 = tx1.clone();
+        block_on(async {
+            stream::iter(0..3).map(Ok).forward(tx1).await.unwrap();
+            tx2.send(3).await.unwrap();
+            tx2.close().await.unwrap();
+            assert_eq!(rx.collect::<Vec<_>>().await, vec![0, 1, 2, 3]);
+        });
+        assert_eq!(block_on(tx2.send(4)), Err(QueueError::Disconnected));
+    }
+
+    #[test]
+    fn test_receiver_waits() {
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        let (mut tx, rx) = unbounded();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let sink = received.clone();
+        spawner
+            .spawn_local(async move {
+                let values = rx.collect::<Vec<_>>().await;
+                sink.borrow_mut().extend(values);
+            })
+            .unwrap();
+        pool.run_until_stalled();
+        assert!(received.borrow().is_empty());
+
+        spawner
+            .spawn_local(async move {
+                for i in 0..5 {
+                    tx.send(i).await.unwrap();
+                }
+            })
+            .unwrap();
+        pool.run();
+        assert_eq!(*received.borrow(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_back_pressure() {
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        let (mut tx, mut rx) = bounded(2);
+        let sent = Rc::new(RefCell::new(0));
+
+        let counter = sent.clone();
+        spawner
+            .spawn_local(async move {
+                for i in 0..5 {
+                    tx.send(i).await.unwrap();
+                    *counter.borrow_mut() += 1;
+                }
+            })
+            .unwrap();
+        pool.run_until_stalled();
+        assert_eq!(*sent.borrow(), 2);
+
+        assert_eq!(pool.run_until(rx.next()), Some(0));
+        pool.run_until_stalled();
+        assert_eq!(*sent.borrow(), 3);
+
+        assert_eq!(pool.run_until(rx.collect::<Vec<_>>()), vec![1, 2, 3, 4]);
+        assert_eq!(*sent.borrow(), 5);
+    }
+
+    #[test]
+    fn test_receiver_dropped() {
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        let (mut tx, rx) = bounded(1);
+        let result = Rc::new(RefCell::new(None));
+
+        let slot = result.clone();
+        spawner
+            .spawn_local(async move {
+                tx.send(1).await.unwrap();
+                *slot.borrow_mut() = Some(tx.send(2).await);
+            })
+            .unwrap();
+        pool.run_until_stalled();
+        assert!(result.borrow().is_none());
+
+        drop(rx);
+        pool.run();
+        assert_eq!(*result.borrow(), Some(Err(QueueError::Disconnected)));
+    }
+
+    #[test]
+    fn test_poll_ready_reserves() {
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let (mut tx1, mut rx) = bounded(1);
+        let mut tx2 = tx1.clone();
+
+        assert_eq!(Pin::new(&mut tx1).poll_ready(&mut cx), Poll::Ready(Ok(())));
+        assert_eq!(Pin::new(&mut tx1).poll_ready(&mut cx), Poll::Ready(Ok(())));
+        assert_eq!(Pin::new(&mut tx2).poll_ready(&mut cx), Poll::Pending);
+        assert_eq!(Pin::new(&mut tx2).start_send(2), Err(QueueError::Full));
+        assert_eq!(Pin::new(&mut tx1).start_send(1), Ok(()));
+        assert_eq!(block_on(rx.next()), Some(1));
+
+        assert_eq!(Pin::new(&mut tx2).poll_ready(&mut cx), Poll::Ready(Ok(())));
+        drop(tx2);
+        assert_eq!(Pin::new(&mut tx1).poll_ready(&mut cx), Poll::Ready(Ok(())));
+        assert_eq!(Pin::new(&mut tx1).start_send(3), Ok(()));
+        drop(tx1);
+        assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![3]);
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod parallel_tests {
+    use super::doublylinkedlist;
+    use super::linkedlist::LinkedList;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_par_iter() {
+        let ll1: LinkedList<_> = (0..1000).collect();
+        assert_eq!(ll1.par_iter().sum::<i32>(), 499500);
+        assert_eq!(
+            ll1.par_iter().map(|value| value * 2).collect::<Vec<_>>(),
+            (0..1000).map(|value| value * 2).collect::<Vec<_>>()
+        );
+
+        let dll1: doublylinkedlist::LinkedList<_> = (0..1000).collect();
+        assert_eq!(dll1.par_iter().sum::<i32>(), 499500);
+        assert_eq!(
+            dll1.par_iter().map(|value| value * 2).collect::<Vec<_>>(),
+            (0..1000).map(|value| value * 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut ll1: LinkedList<_> = (0..1000).collect();
+        ll1.par_iter_mut().for_each(|value| *value *= 3);
+        assert!(ll1
+            .iter()
+            .enumerate()
+            .all(|(i, value)| *value == i as i32 * 3));
+
+        let mut dll1: doublylinkedlist::LinkedList<_> = (0..1000).collect();
+        dll1.rotate_left(300);
+        dll1.pop_front().unwrap();
+        dll1.push_front(-1);
+        dll1.par_iter_mut().for_each(|value| *value *= 3);
+        assert_eq!(dll1.len(), 1000);
+        assert!(dll1
+            .iter()
+            .copied()
+            .eq(std::iter::once(-3).chain((301..1000).chain(0..300).map(|value| value * 3))));
+        assert_eq!(
+            dll1.par_iter_mut().map(|value| *value).collect::<Vec<_>>(),
+            dll1.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_into_par_iter() {
+        let ll1: LinkedList<_> = (0..1000).collect();
+        let ll2: LinkedList<_> = ll1.into_par_iter().map(|value| value + 1).collect();
+        assert_eq!(ll2.len(), 1000);
+        assert!(ll2.iter().copied().eq(1..1001));
+
+        let dll1: doublylinkedlist::LinkedList<_> = (0..1000).collect();
+        let dll2: doublylinkedlist::LinkedList<_> = dll1
+            .into_par_iter()
+            .filter(|value| value % 2 == 0)
+            .collect();
+        assert_eq!(dll2.len(), 500);
+        assert!(dll2.iter().copied().eq((0..1000).step_by(2)));
+    }
+
+    #[test]
+    fn test_par_extend() {
+        let mut ll1 = LinkedList::from([-2, -1]);
+        ll1.par_extend(0..1000);
+        ll1.par_extend(Vec::new());
+        assert_eq!(ll1.len(), 1002);
+        assert!(ll1.iter().copied().eq(-2..1000));
+
+        let mut ll2 = LinkedList::new();
+        ll2.par_extend((0..10).into_par_iter().filter(|value| value % 3 == 0));
+        assert_eq!([0, 3, 6, 9], ll2);
+
+        let mut dll1 = doublylinkedlist::LinkedList::from([-1]);
+        dll1.par_extend(0..100);
+        assert_eq!(dll1.len(), 101);
+        assert!(dll1.iter().copied().eq(-1..100));
+    }
 }
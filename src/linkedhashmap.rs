@@ -0,0 +1,358 @@
+use crate::doublylinkedlist::{self, LinkedList, NodeHandle};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// A hash map that remembers the order in which keys were inserted.
+pub struct LinkedHashMap<K, V> {
+    /// The entries, each with a handle to its key's node in `order`.
+    map: HashMap<K, (V, NodeHandle<K>)>,
+    /// The keys in insertion order.
+    order: LinkedList<K>,
+}
+
+impl<K: Clone + Hash + Eq, V> LinkedHashMap<K, V> {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            order: LinkedList::new(),
+        }
+    }
+
+    /// Get the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Insert an entry at the back of the map, returning the old value if the
+    /// key was already present. An existing key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some((old_value, _)) = self.map.get_mut(&key) {
+            return Some(std::mem::replace(old_value, value));
+        }
+
+        let handle = self.order.push_back_handle(key.clone());
+        self.map.insert(key, (value, handle));
+
+        None
+    }
+
+    /// Remove an entry, returning its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove an entry, returning its key and value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (key, (value, handle)) = self.map.remove_entry(key)?;
+        self.order.remove_handle(&handle).unwrap();
+
+        Some((key, value))
+    }
+
+    /// Get a reference to a value.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|(value, _)| value)
+    }
+
+    /// Get a mutable reference to a value.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_mut(key).map(|(value, _)| value)
+    }
+
+    /// Check if the map contains a key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Move an entry to the front of the map, returning whether the key was
+    /// present.
+    pub fn move_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get(key) {
+            Some((_, handle)) => {
+                self.order.move_handle_to_front(handle).unwrap();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move an entry to the back of the map, returning whether the key was
+    /// present.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get(key) {
+            Some((_, handle)) => {
+                self.order.move_handle_to_back(handle).unwrap();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the entry at the front of the map.
+    pub fn front(&self) -> Option<(&K, &V)> {
+        let key = self.order.front()?;
+        let (key, (value, _)) = self.map.get_key_value(key)?;
+
+        Some((key, value))
+    }
+
+    /// Get the entry at the back of the map.
+    pub fn back(&self) -> Option<(&K, &V)> {
+        let key = self.order.back()?;
+        let (key, (value, _)) = self.map.get_key_value(key)?;
+
+        Some((key, value))
+    }
+
+    /// Remove the entry at the front of the map, returning it.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let key = self.order.pop_front().ok()?;
+        let (value, _) = self.map.remove(&key)?;
+
+        Some((key, value))
+    }
+
+    /// Remove the entry at the back of the map, returning it.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let key = self.order.pop_back().ok()?;
+        let (value, _) = self.map.remove(&key)?;
+
+        Some((key, value))
+    }
+
+    /// Clear the map.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    /// Returns an iterator over the entries in insertion order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            keys: self.order.iter(),
+            map: &self.map,
+        }
+    }
+
+    /// Returns an iterator over the keys in insertion order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    /// Returns an iterator over the values in insertion order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Clone + Hash + Eq, V> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Hash + Eq + fmt::Debug, V: fmt::Debug> fmt::Debug for LinkedHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone> Clone for LinkedHashMap<K, V> {
+    fn clone(&self) -> Self {
+        self.iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: PartialEq> PartialEq for LinkedHashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Clone + Hash + Eq, V> Extend<(K, V)> for LinkedHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Clone + Hash + Eq, V> FromIterator<(K, V)> for LinkedHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Clone + Hash + Eq, V, const N: usize> From<[(K, V); N]> for LinkedHashMap<K, V> {
+    fn from(arr: [(K, V); N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+/// An iterator over the entries of a linked hash map in insertion order.
+pub struct Iter<'a, K, V> {
+    /// The keys in insertion order.
+    keys: doublylinkedlist::Iter<'a, K>,
+    /// The entries.
+    map: &'a HashMap<K, (V, NodeHandle<K>)>,
+}
+
+impl<'a, K: Clone + Hash + Eq, V> Iter<'a, K, V> {
+    /// Look up the entry for a key yielded by the key iterator.
+    fn entry(&self, key: &K) -> Option<(&'a K, &'a V)> {
+        let (key, (value, _)) = self.map.get_key_value(key)?;
+
+        Some((key, value))
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        self.entry(key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next_back()?;
+        self.entry(key)
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+/// An iterator over the keys of a linked hash map in insertion order.
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values of a linked hash map in insertion order.
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: Clone + Hash + Eq, V> IntoIterator for &'a LinkedHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An owning iterator over the entries of a linked hash map in insertion
+/// order.
+pub struct IntoIter<K, V>(LinkedHashMap<K, V>);
+
+impl<K: Clone + Hash + Eq, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<K: Clone + Hash + Eq, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<K: Clone + Hash + Eq, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K: Clone + Hash + Eq, V> IntoIterator for LinkedHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
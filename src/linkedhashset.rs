@@ -0,0 +1,203 @@
+use crate::linkedhashmap::{self, LinkedHashMap};
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// A hash set that remembers the order in which values were inserted.
+pub struct LinkedHashSet<T> {
+    /// The values, stored as the keys of a map.
+    map: LinkedHashMap<T, ()>,
+}
+
+impl<T: Clone + Hash + Eq> LinkedHashSet<T> {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self {
+            map: LinkedHashMap::new(),
+        }
+    }
+
+    /// Get the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Insert a value at the back of the set, returning whether it was newly
+    /// inserted. An existing value keeps its position.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Remove a value, returning whether it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Remove a value, returning it.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+
+    /// Check if the set contains a value.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Move a value to the front of the set, returning whether it was present.
+    pub fn move_to_front<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.move_to_front(value)
+    }
+
+    /// Move a value to the back of the set, returning whether it was present.
+    pub fn move_to_back<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.move_to_back(value)
+    }
+
+    /// Get the value at the front of the set.
+    pub fn front(&self) -> Option<&T> {
+        self.map.front().map(|(value, _)| value)
+    }
+
+    /// Get the value at the back of the set.
+    pub fn back(&self) -> Option<&T> {
+        self.map.back().map(|(value, _)| value)
+    }
+
+    /// Remove the value at the front of the set, returning it.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.map.pop_front().map(|(value, _)| value)
+    }
+
+    /// Remove the value at the back of the set, returning it.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.map.pop_back().map(|(value, _)| value)
+    }
+
+    /// Clear the set.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the values in insertion order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.map.keys()
+    }
+}
+
+/// An iterator over the values of a linked hash set in insertion order.
+pub type Iter<'a, T> = linkedhashmap::Keys<'a, T, ()>;
+
+impl<T: Clone + Hash + Eq> Default for LinkedHashSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Hash + Eq + fmt::Debug> fmt::Debug for LinkedHashSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone + Hash + Eq> Clone for LinkedHashSet<T> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Hash + Eq> PartialEq for LinkedHashSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Clone + Hash + Eq> Extend<T> for LinkedHashSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Clone + Hash + Eq> FromIterator<T> for LinkedHashSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Clone + Hash + Eq, const N: usize> From<[T; N]> for LinkedHashSet<T> {
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<'a, T: Clone + Hash + Eq> IntoIterator for &'a LinkedHashSet<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An owning iterator over the values of a linked hash set in insertion order.
+pub struct IntoIter<T>(linkedhashmap::IntoIter<T, ()>);
+
+impl<T: Clone + Hash + Eq> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T: Clone + Hash + Eq> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<T: Clone + Hash + Eq> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone + Hash + Eq> IntoIterator for LinkedHashSet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.map.into_iter())
+    }
+}
@@ -1,17 +1,37 @@
 #![allow(dead_code)]
 
-use std::iter::{FromIterator, IntoIterator};
-use std::ops::{Deref, DerefMut, Index, IndexMut};
-use std::vec::IntoIter;
+#[cfg(feature = "std")]
+use crate::doublylinkedlist;
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::boxed::Box;
+use core::cmp::Ordering;
+use core::iter::{FromIterator, IntoIterator};
+use core::ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds};
 
 /// Linked list errors.
 pub enum LinkedListError {
-    IndexOutOfBounds { index: usize, size: usize },
-    InvalidArraySize { size: usize, array_size: usize },
+    IndexOutOfBounds {
+        index: usize,
+        size: usize,
+    },
+    InvalidArraySize {
+        size: usize,
+        array_size: usize,
+    },
+    CapacityExceeded {
+        capacity: usize,
+    },
+    InvalidRange {
+        start: usize,
+        end: usize,
+        size: usize,
+    },
 }
 
-impl std::fmt::Debug for LinkedListError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for LinkedListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match *self {
             Self::IndexOutOfBounds { index, size } => write!(
                 f,
@@ -23,95 +43,644 @@ impl std::fmt::Debug for LinkedListError {
                 "invalid array size; linked list size: {}, array size: {}",
                 size, array_size
             ),
+            Self::CapacityExceeded { capacity } => {
+                write!(f, "capacity exceeded; linked list capacity: {}", capacity)
+            }
+            Self::InvalidRange { start, end, size } => write!(
+                f,
+                "invalid range; linked list size: {}, provided range: {}..{}",
+                size, start, end
+            ),
         }
     }
 }
 
+impl core::fmt::Debug for LinkedListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+impl core::error::Error for LinkedListError {}
+
 /// Linked list result type.
 pub type Result<T> = core::result::Result<T, LinkedListError>;
 
+/// The result of an operation that hands its value back on failure.
+pub type TryResult<T> = core::result::Result<(), (LinkedListError, T)>;
+
+/// Panic for an out of bounds index, with the same message as `Vec`.
+#[track_caller]
+pub(crate) fn index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!(
+        "index out of bounds: the len is {} but the index is {}",
+        len, index
+    )
+}
+
+/// Move a value out of its box, returning the box's memory to its allocator.
+#[cfg(feature = "allocator_api")]
+fn unbox<T, A: Allocator>(boxed: Box<T, A>) -> T {
+    *boxed
+}
+
+/// Move a value out of its box, returning the box's memory to its allocator.
+#[cfg(not(feature = "allocator_api"))]
+fn unbox<T, A: Allocator>(boxed: Box<T, A>) -> T {
+    Box::into_inner(boxed)
+}
+
 /// An iterator over the elements of a linked list.
-pub struct Iter<'a, T> {
+///
+/// Nodes only link forwards, so the first call to `next_back` walks the
+/// elements not yet yielded into a buffer, taking linear time and allocating
+/// space for them. Iterating only from the front does not allocate.
+pub struct Iter<'a, T, A: Allocator = Global> {
     /// A reference to the current node.
-    current_node: Option<&'a LinkedListNode<T>>,
+    current_node: Option<&'a LinkedListNode<T, A>>,
+    /// The number of elements not yet yielded.
+    remaining: usize,
+    /// The elements not yet yielded, collected the first time the iterator is
+    /// advanced from the back, since nodes only link forwards.
+    buffer: Option<VecDeque<&'a T>>,
 }
 
-impl<'a, T> Iter<'a, T> {
+impl<'a, T, A: Allocator + Clone> Iter<'a, T, A> {
     /// Create an iterator from a linked list.
-    pub fn new(ll: &'a LinkedList<T>) -> Self {
+    pub fn new(ll: &'a LinkedList<T, A>) -> Self {
         Self {
             current_node: ll.head.as_ref(),
+            remaining: ll.size,
+            buffer: None,
         }
     }
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
+impl<'a, T, A: Allocator> Iterator for Iter<'a, T, A> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(buffer) = &mut self.buffer {
+            return buffer.pop_front();
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
         match self.current_node {
             Some(node) => {
                 self.current_node = node.get_next();
+                self.remaining -= 1;
                 Some(&**node)
             }
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match &self.buffer {
+            Some(buffer) => buffer.len(),
+            None => self.remaining,
+        };
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Iter<'_, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let buffer = self.buffer.get_or_insert_with(|| {
+            let mut buffer = VecDeque::with_capacity(self.remaining);
+            let mut current = self.current_node.take();
+
+            while let Some(node) = current.filter(|_| buffer.len() < self.remaining) {
+                buffer.push_back(&**node);
+                current = node.get_next();
+            }
+
+            buffer
+        });
+
+        buffer.pop_back()
+    }
 }
 
+impl<T, A: Allocator> ExactSizeIterator for Iter<'_, T, A> {}
+
 /// An iterator over the elements of a linked list, allowing for them to be mutated.
-pub struct IterMut<'a, T> {
+///
+/// Nodes only link forwards, so the first call to `next_back` walks the
+/// elements not yet yielded into a buffer, taking linear time and allocating
+/// space for them. Iterating only from the front does not allocate.
+pub struct IterMut<'a, T, A: Allocator = Global> {
     /// A reference to the current node.
-    current_node: Option<&'a mut LinkedListNode<T>>,
+    current_node: Option<&'a mut LinkedListNode<T, A>>,
+    /// The number of elements not yet yielded.
+    remaining: usize,
+    /// The elements not yet yielded, collected the first time the iterator is
+    /// advanced from the back, since nodes only link forwards.
+    buffer: Option<VecDeque<&'a mut T>>,
 }
 
-impl<'a, T> IterMut<'a, T> {
+impl<'a, T, A: Allocator + Clone> IterMut<'a, T, A> {
     /// Create a mutable iterator from a linked list.
-    pub fn new(ll: &'a mut LinkedList<T>) -> Self {
+    pub fn new(ll: &'a mut LinkedList<T, A>) -> Self {
         Self {
+            remaining: ll.size,
             current_node: ll.head.as_mut(),
+            buffer: None,
         }
     }
 }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
+impl<'a, T, A: Allocator> Iterator for IterMut<'a, T, A> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(buffer) = &mut self.buffer {
+            return buffer.pop_front();
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
         match self.current_node.take() {
             Some(node) => {
                 let (value, next) = node.get_mut();
                 self.current_node = next;
+                self.remaining -= 1;
                 Some(value)
             }
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match &self.buffer {
+            Some(buffer) => buffer.len(),
+            None => self.remaining,
+        };
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IterMut<'_, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let buffer = self.buffer.get_or_insert_with(|| {
+            let mut buffer = VecDeque::with_capacity(self.remaining);
+            let mut current = self.current_node.take();
+
+            while let Some(node) = current.filter(|_| buffer.len() < self.remaining) {
+                let (value, next) = node.get_mut();
+                buffer.push_back(value);
+                current = next;
+            }
+
+            buffer
+        });
+
+        buffer.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IterMut<'_, T, A> {}
+
+/// A borrowed view of a contiguous range of a linked list.
+pub struct Range<'a, T, A: Allocator = Global> {
+    /// The first node in the range.
+    first_node: Option<&'a LinkedListNode<T, A>>,
+    /// The number of nodes in the range.
+    size: usize,
+}
+
+impl<'a, T, A: Allocator> Range<'a, T, A> {
+    /// Get the number of nodes in the range.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Check if the range contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get a reference to the value at an index within the range.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns an iterator over the elements in the range.
+    pub fn iter(&self) -> Iter<'a, T, A> {
+        Iter {
+            current_node: self.first_node,
+            remaining: self.size,
+            buffer: None,
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for Range<'a, T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A mutable borrowed view of a contiguous range of a linked list.
+pub struct RangeMut<'a, T, A: Allocator = Global> {
+    /// The first node in the range.
+    first_node: Option<&'a mut LinkedListNode<T, A>>,
+    /// The number of nodes in the range.
+    size: usize,
+}
+
+impl<'a, T, A: Allocator> RangeMut<'a, T, A> {
+    /// Get the number of nodes in the range.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Check if the range contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get a reference to the value at an index within the range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Get a mutable reference to the value at an index within the range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    /// Returns an iterator over the elements in the range.
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter {
+            current_node: self.first_node.as_deref(),
+            remaining: self.size,
+            buffer: None,
+        }
+    }
+
+    /// Returns an iterator over the elements in the range, allowing for them
+    /// to be mutated.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+        IterMut {
+            current_node: self.first_node.as_deref_mut(),
+            remaining: self.size,
+            buffer: None,
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for RangeMut<'a, T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            current_node: self.first_node,
+            remaining: self.size,
+            buffer: None,
+        }
+    }
+}
+
+/// An iterator that moves the elements out of a linked list.
+pub struct IntoIter<T, A: Allocator + Clone = Global>(LinkedList<T, A>);
+
+impl<T, A: Allocator + Clone> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.size, Some(self.0.size))
+    }
 }
 
+impl<T, A: Allocator + Clone> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator + Clone> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back().ok()
+    }
+}
+
+/// A chain of boxed nodes.
+type Link<T, A> = Option<Box<LinkedListNode<T, A>, A>>;
+
+/// The length below which a chain is sorted on the current thread.
+#[cfg(feature = "std")]
+pub(crate) const PARALLEL_SORT_THRESHOLD: usize = 4096;
+
+/// Get how many times a parallel sort should split its input across threads.
+#[cfg(feature = "std")]
+pub(crate) fn parallel_sort_depth() -> u32 {
+    std::thread::available_parallelism().map_or(0, |threads| {
+        threads.get().next_power_of_two().trailing_zeros()
+    })
+}
+
+/// Split a chain after its first `len` nodes, returning the rest.
+fn split_link<T, A: Allocator>(link: &mut Link<T, A>, len: usize) -> Link<T, A> {
+    let mut current = link;
+
+    for _ in 0..len {
+        current = &mut current.as_mut().unwrap().next;
+    }
+
+    current.take()
+}
+
+/// Merge two sorted chains by relinking their nodes. On ties, nodes from `a`
+/// come first.
+fn merge_links<T, A: Allocator, F: FnMut(&T, &T) -> Ordering>(
+    mut a: Link<T, A>,
+    mut b: Link<T, A>,
+    compare: &mut F,
+) -> Link<T, A> {
+    let mut merged = None;
+    let mut tail = &mut merged;
+
+    while let (Some(x), Some(y)) = (&a, &b) {
+        let source = if compare(&y.value, &x.value) == Ordering::Less {
+            &mut b
+        } else {
+            &mut a
+        };
+        let mut node = source.take().unwrap();
+        *source = node.next.take();
+        tail = &mut tail.insert(node).next;
+    }
+
+    *tail = a.or(b);
+
+    merged
+}
+
+/// Stably sort a chain of `len` nodes by relinking them.
+fn sort_link<T, A: Allocator, F: FnMut(&T, &T) -> Ordering>(
+    mut link: Link<T, A>,
+    len: usize,
+    compare: &mut F,
+) -> Link<T, A> {
+    if len <= 1 {
+        return link;
+    }
+
+    let mid = len / 2;
+    let rest = split_link(&mut link, mid);
+    let left = sort_link(link, mid, compare);
+    let right = sort_link(rest, len - mid, compare);
+
+    merge_links(left, right, compare)
+}
+
+/// Stably sort a chain of `len` nodes by relinking them, sorting halves on
+/// separate threads `depth` levels deep.
+#[cfg(feature = "std")]
+fn par_sort_link<T, A, F>(mut link: Link<T, A>, len: usize, depth: u32, compare: &F) -> Link<T, A>
+where
+    T: Send,
+    A: Allocator + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if depth == 0 || len < PARALLEL_SORT_THRESHOLD {
+        return sort_link(link, len, &mut |a, b| compare(a, b));
+    }
+
+    let mid = len / 2;
+    let rest = split_link(&mut link, mid);
+    let (left, right) = std::thread::scope(|scope| {
+        let right = scope.spawn(move || par_sort_link(rest, len - mid, depth - 1, compare));
+        let left = par_sort_link(link, mid, depth - 1, compare);
+
+        (left, right.join().unwrap())
+    });
+
+    merge_links(left, right, &mut |a, b| compare(a, b))
+}
+
+/// Which nodes a combination of two sorted chains keeps.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SetOperation {
+    Merge,
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// A node taken from the front of a linked list being taken apart.
+enum Taken<T, A: Allocator> {
+    /// The linked list's inline first node.
+    Inline(LinkedListNode<T, A>),
+    /// A boxed later node.
+    Boxed(Box<LinkedListNode<T, A>, A>),
+}
+
+/// The nodes of a linked list being taken apart from the front, without
+/// boxing its inline first node.
+struct Chain<T, A: Allocator> {
+    /// The inline first node, until it is taken.
+    head: Option<LinkedListNode<T, A>>,
+    /// The boxed nodes after it.
+    rest: Link<T, A>,
+}
+
+impl<T, A: Allocator> Chain<T, A> {
+    /// Start taking apart a linked list with a given first node.
+    fn new(head: Option<LinkedListNode<T, A>>) -> Self {
+        Self { head, rest: None }
+    }
+
+    /// Get a reference to the value of the next node.
+    fn peek(&self) -> Option<&T> {
+        self.head
+            .as_ref()
+            .or(self.rest.as_deref())
+            .map(|node| &node.value)
+    }
+
+    /// Detach the next node.
+    fn pop(&mut self) -> Option<Taken<T, A>> {
+        if let Some(mut head) = self.head.take() {
+            self.rest = head.next.take();
+            return Some(Taken::Inline(head));
+        }
+
+        let mut node = self.rest.take()?;
+        self.rest = node.next.take();
+
+        Some(Taken::Boxed(node))
+    }
+}
+
+/// A linked list being built by appending taken nodes.
+struct ChainBuilder<T, A: Allocator> {
+    /// The first node, kept inline.
+    head: Option<LinkedListNode<T, A>>,
+    /// The number of nodes appended.
+    len: usize,
+    /// The allocator for boxing inline nodes that do not come first.
+    alloc: A,
+}
+
+impl<T, A: Allocator + Clone> ChainBuilder<T, A> {
+    /// Start an empty linked list.
+    fn new(alloc: A) -> Self {
+        Self {
+            head: None,
+            len: 0,
+            alloc,
+        }
+    }
+
+    /// Append a taken node after `tail`, the end of the boxed chain after the
+    /// first node, returning the new end. Boxed nodes are relinked, and only
+    /// an inline node that does not come first is boxed.
+    fn append<'a>(&mut self, tail: &'a mut Link<T, A>, node: Taken<T, A>) -> &'a mut Link<T, A> {
+        self.len += 1;
+
+        if self.head.is_none() {
+            self.head = Some(match node {
+                Taken::Inline(node) => node,
+                Taken::Boxed(node) => unbox(node),
+            });
+
+            return tail;
+        }
+
+        let node = match node {
+            Taken::Inline(node) => Box::new_in(node, self.alloc.clone()),
+            Taken::Boxed(node) => node,
+        };
+
+        &mut tail.insert(node).next
+    }
+
+    /// Finish the linked list, linking the boxed chain after the first node.
+    fn finish(self, rest: Link<T, A>) -> LinkedList<T, A> {
+        LinkedList {
+            head: self.head.map(|mut head| {
+                head.next = rest;
+                head
+            }),
+            size: self.len,
+            alloc: self.alloc,
+        }
+    }
+}
+
+/// Combine two sorted linked lists by relinking their nodes. Nodes that are
+/// not kept are dropped one at a time. On ties, nodes from `a` come first.
+fn combine_chains<T: Ord, A: Allocator + Clone>(
+    mut a: Chain<T, A>,
+    mut b: Chain<T, A>,
+    operation: SetOperation,
+    alloc: A,
+) -> LinkedList<T, A> {
+    use SetOperation::*;
+
+    let mut combined = ChainBuilder::new(alloc);
+    let mut rest = None;
+    let mut tail = &mut rest;
+
+    loop {
+        let (node, keep) = match (a.peek(), b.peek()) {
+            (None, None) => break,
+            (Some(_), None) => (a.pop(), operation != Intersection),
+            (None, Some(_)) => (
+                b.pop(),
+                matches!(operation, Merge | Union | SymmetricDifference),
+            ),
+            (Some(x), Some(y)) => match x.cmp(y) {
+                Ordering::Less => (a.pop(), operation != Intersection),
+                Ordering::Greater => (
+                    b.pop(),
+                    matches!(operation, Merge | Union | SymmetricDifference),
+                ),
+                Ordering::Equal if operation == Merge => (a.pop(), true),
+                Ordering::Equal => {
+                    b.pop();
+                    (a.pop(), matches!(operation, Union | Intersection))
+                }
+            },
+        };
+
+        if let (Some(node), true) = (node, keep) {
+            tail = combined.append(tail, node);
+        }
+    }
+
+    combined.finish(rest)
+}
+
+/// The remaining nodes of one of the lists in a k-way merge, ordered by their
+/// first value so that the max-heap yields the smallest value, and on ties the
+/// earliest list. The chain is never empty.
+struct MergeHead<T, A: Allocator> {
+    chain: Chain<T, A>,
+    list: usize,
+}
+
+impl<T: Ord, A: Allocator> Ord for MergeHead<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .chain
+            .peek()
+            .cmp(&self.chain.peek())
+            .then(other.list.cmp(&self.list))
+    }
+}
+
+impl<T: Ord, A: Allocator> PartialOrd for MergeHead<T, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, A: Allocator> PartialEq for MergeHead<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord, A: Allocator> Eq for MergeHead<T, A> {}
+
 /// A node in a linked list.
 #[derive(Clone, Debug)]
-struct LinkedListNode<T> {
+struct LinkedListNode<T, A: Allocator = Global> {
     /// The node's value.
     value: T,
     /// The next node in the linked list.
-    next: Option<Box<LinkedListNode<T>>>,
+    next: Option<Box<LinkedListNode<T, A>, A>>,
 }
 
-impl<T> LinkedListNode<T> {
+impl<T, A: Allocator> LinkedListNode<T, A> {
     /// Take the node's value and next node, consuming the node itself.
-    pub fn take(self) -> (T, Option<LinkedListNode<T>>) {
-        (self.value, self.next.map(|next| *next))
+    pub fn take(self) -> (T, Option<LinkedListNode<T, A>>) {
+        (self.value, self.next.map(unbox))
     }
 
     /// Get references to the value and next node in the linked list.
-    pub fn get(&self) -> (&T, Option<&LinkedListNode<T>>) {
-        (&self.value, self.next.as_ref().map(|next| &**next))
+    pub fn get(&self) -> (&T, Option<&LinkedListNode<T, A>>) {
+        (&self.value, self.next.as_deref())
     }
 
     /// Get mutable references to the value and next node in the linked list.
-    pub fn get_mut(&mut self) -> (&mut T, Option<&mut LinkedListNode<T>>) {
-        (&mut self.value, self.next.as_mut().map(|next| &mut **next))
+    pub fn get_mut(&mut self) -> (&mut T, Option<&mut LinkedListNode<T, A>>) {
+        (&mut self.value, self.next.as_deref_mut())
     }
 
     /// Get a reference to the node's value.
@@ -140,32 +709,33 @@ impl<T> LinkedListNode<T> {
     }
 
     /// Get an optional reference to the next node.
-    pub fn get_next(&self) -> Option<&LinkedListNode<T>> {
-        self.next.as_ref().map(|next| &**next)
+    pub fn get_next(&self) -> Option<&LinkedListNode<T, A>> {
+        self.next.as_deref()
     }
 
     /// Get an optional mutable reference to the next node.
-    pub fn get_next_mut(&mut self) -> Option<&mut LinkedListNode<T>> {
-        self.next.as_mut().map(|next| &mut **next)
+    pub fn get_next_mut(&mut self) -> Option<&mut LinkedListNode<T, A>> {
+        self.next.as_deref_mut()
     }
 
     /// Take ownership of the next node, removing it from the list.
-    pub fn take_next(&mut self) -> Option<LinkedListNode<T>> {
-        self.next.take().map(|next| *next)
+    pub fn take_next(&mut self) -> Option<LinkedListNode<T, A>> {
+        self.next.take().map(unbox)
     }
 
-    /// Set the node's next node.
-    pub fn set_next(&mut self, next: Self) {
-        self.next = Some(Box::new(next));
+    /// Set the node's next node, allocating it with `alloc`.
+    pub fn set_next(&mut self, next: Self, alloc: A) {
+        self.next = Some(Box::new_in(next, alloc));
     }
 
-    /// Set an empty next node with the specified value.
-    pub fn set_next_by_value(&mut self, value: T) {
-        self.next = Some(Box::new(Self { value, next: None }));
+    /// Set an empty next node with the specified value, allocating it with
+    /// `alloc`.
+    pub fn set_next_by_value(&mut self, value: T, alloc: A) {
+        self.next = Some(Box::new_in(Self { value, next: None }, alloc));
     }
 }
 
-impl<T> Deref for LinkedListNode<T> {
+impl<T, A: Allocator> Deref for LinkedListNode<T, A> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -173,35 +743,98 @@ impl<T> Deref for LinkedListNode<T> {
     }
 }
 
-impl<T> DerefMut for LinkedListNode<T> {
+impl<T, A: Allocator> DerefMut for LinkedListNode<T, A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.value
     }
 }
 
-/// A linked list.
+/// A linked list whose nodes are allocated with `A`.
+///
+/// A linked list compares equal to linked lists, slices, arrays, `Vec`s and
+/// `VecDeque`s of any comparable element type, on either side of `==`.
 #[derive(Clone, Debug)]
-pub struct LinkedList<T> {
+pub struct LinkedList<T, A: Allocator + Clone = Global> {
     /// The first node in the linked list.
-    head: Option<LinkedListNode<T>>,
+    head: Option<LinkedListNode<T, A>>,
     /// The total number of nodes in the linked list.
     size: usize,
+    /// The allocator used for the nodes.
+    alloc: A,
 }
 
 impl<T> LinkedList<T> {
     /// Create an empty linked list.
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Create an empty linked list whose nodes are allocated with `alloc`.
+    pub fn new_in(alloc: A) -> Self {
         Self {
             head: None,
             size: 0,
+            alloc,
         }
     }
 
+    /// Get a reference to the allocator used for the nodes.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Get the number of nodes in the linked list.
     pub fn len(&self) -> usize {
         self.size
     }
 
+    /// Check if the linked list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get a reference to the value at the start of the linked list.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|head| head.get_value())
+    }
+
+    /// Get a mutable reference to the value at the start of the linked list.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|head| head.get_value_mut())
+    }
+
+    /// Get a reference to the value at the end of the linked list.
+    pub fn back(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Get a mutable reference to the value at the end of the linked list.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().last()
+    }
+
+    /// Get a reference to the first value. This is the same as `front`.
+    pub fn first(&self) -> Option<&T> {
+        self.front()
+    }
+
+    /// Get a reference to the last value. This is the same as `back`.
+    pub fn last(&self) -> Option<&T> {
+        self.back()
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.get_value(index).ok()
+    }
+
+    /// Get a mutable reference to the value at a given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.get_value_mut(index).ok()
+    }
+
     /// Get a reference to the value at a node by index.
     pub fn get_value(&self, index: usize) -> Result<&T> {
         if index < self.size {
@@ -240,6 +873,11 @@ impl<T> LinkedList<T> {
 
     /// Set the value at a node by index.
     pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
+        self.try_set_value(index, value).map_err(|(error, _)| error)
+    }
+
+    /// Set the value at a node by index, handing the value back on failure.
+    pub fn try_set_value(&mut self, index: usize, value: T) -> TryResult<T> {
         if index < self.size {
             let mut current = self.head.as_mut().unwrap();
 
@@ -251,15 +889,23 @@ impl<T> LinkedList<T> {
 
             Ok(())
         } else {
-            Err(LinkedListError::IndexOutOfBounds {
+            let error = LinkedListError::IndexOutOfBounds {
                 index,
                 size: self.size,
-            })
+            };
+
+            Err((error, value))
         }
     }
 
     /// Insert a value in a new node at a given index in the linked list.
     pub fn push(&mut self, index: usize, value: T) -> Result<()> {
+        self.try_push(index, value).map_err(|(error, _)| error)
+    }
+
+    /// Insert a value in a new node at a given index in the linked list,
+    /// handing the value back on failure.
+    pub fn try_push(&mut self, index: usize, value: T) -> TryResult<T> {
         if index == 0 {
             self.push_front(value);
 
@@ -272,18 +918,24 @@ impl<T> LinkedList<T> {
             }
 
             let current_next = current.take_next();
-            current.set_next(LinkedListNode {
-                value,
-                next: current_next.map(|value| Box::new(value)),
-            });
+            let alloc = self.alloc.clone();
+            current.set_next(
+                LinkedListNode {
+                    value,
+                    next: current_next.map(|next| Box::new_in(next, alloc.clone())),
+                },
+                alloc,
+            );
             self.size += 1;
 
             Ok(())
         } else {
-            Err(LinkedListError::IndexOutOfBounds {
+            let error = LinkedListError::IndexOutOfBounds {
                 index,
                 size: self.size,
-            })
+            };
+
+            Err((error, value))
         }
     }
 
@@ -292,7 +944,7 @@ impl<T> LinkedList<T> {
         let next = self.head.take();
         self.head = Some(LinkedListNode {
             value,
-            next: next.map(|value| Box::new(value)),
+            next: next.map(|next| Box::new_in(next, self.alloc.clone())),
         });
         self.size += 1;
     }
@@ -306,7 +958,7 @@ impl<T> LinkedList<T> {
                 current = current.get_next_mut().unwrap();
             }
 
-            current.set_next_by_value(value);
+            current.set_next_by_value(value, self.alloc.clone());
         } else {
             self.head = Some(LinkedListNode { value, next: None });
         }
@@ -328,7 +980,7 @@ impl<T> LinkedList<T> {
             let mut node = current.take_next().unwrap();
 
             if node.has_next() {
-                current.set_next(node.take_next().unwrap());
+                current.set_next(node.take_next().unwrap(), self.alloc.clone());
             }
 
             self.size -= 1;
@@ -384,48 +1036,509 @@ impl<T> LinkedList<T> {
         self.size = 0;
     }
 
+    /// Shorten the linked list to `len` nodes, dropping the rest. Does
+    /// nothing if the linked list is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.size {
+            return;
+        }
+
+        let mut rest = if len == 0 {
+            self.head.take()
+        } else {
+            let mut current = self.head.as_mut().unwrap();
+
+            for _ in 0..len - 1 {
+                current = current.get_next_mut().unwrap();
+            }
+
+            current.take_next()
+        };
+
+        while let Some(mut node) = rest {
+            rest = node.take_next();
+        }
+
+        self.size = len;
+    }
+
+    /// Resize the linked list to `len` nodes, filling new nodes at the end
+    /// with values returned by `f`.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, len: usize, f: F) {
+        if len <= self.size {
+            self.truncate(len);
+        } else {
+            self.push_back_all(core::iter::repeat_with(f).take(len - self.size));
+        }
+    }
+
+    /// Insert values at the end of the linked list, walking to the end only
+    /// once.
+    pub(crate) fn push_back_all<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        let mut values = values.into_iter();
+
+        if self.size == 0 {
+            match values.next() {
+                Some(value) => self.push_front(value),
+                None => return,
+            }
+        }
+
+        let mut current = self.head.as_mut().unwrap();
+
+        while current.has_next() {
+            current = current.get_next_mut().unwrap();
+        }
+
+        for value in values {
+            current.set_next_by_value(value, self.alloc.clone());
+            current = current.get_next_mut().unwrap();
+            self.size += 1;
+        }
+    }
+
+    /// Unlink consecutive duplicates in a single pass, passing each removed
+    /// value to `removed`.
+    fn dedup_by_with<F, R>(&mut self, mut same_bucket: F, mut removed: R)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+        R: FnMut(T),
+    {
+        let Some(mut current) = self.head.as_mut() else {
+            return;
+        };
+        let mut count = 0;
+
+        while let Some(next) = current.next.as_deref_mut() {
+            if same_bucket(&mut next.value, &mut current.value) {
+                let mut node = current.next.take().unwrap();
+                current.next = node.next.take();
+                count += 1;
+                removed(unbox(node).value);
+            } else {
+                current = current.next.as_deref_mut().unwrap();
+            }
+        }
+
+        self.size -= count;
+    }
+
+    /// Remove consecutive values for which `same_bucket(value, previous)`
+    /// returns true, keeping the first value of each run. Duplicate nodes are
+    /// unlinked in a single pass.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+        self.dedup_by_with(same_bucket, drop);
+    }
+
+    /// Remove consecutive values that map to the same key, keeping the first
+    /// value of each run.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Remove consecutive values for which `same_bucket(value, previous)`
+    /// returns true like `dedup_by`, returning the removed values in order.
+    pub fn dedup_by_drain<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.dedup_by_with(same_bucket, |value| removed.push(value));
+
+        removed
+    }
+
+    /// Swap the values at two indices.
+    ///
+    /// Panics if either index is out of bounds.
+    #[track_caller]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        if i >= self.size {
+            index_out_of_bounds(i, self.size);
+        }
+
+        if j >= self.size {
+            index_out_of_bounds(j, self.size);
+        }
+
+        if i == j {
+            return;
+        }
+
+        let (i, j) = (i.min(j), i.max(j));
+        let mut current = self.head.as_mut().unwrap();
+
+        for _ in 0..i {
+            current = current.get_next_mut().unwrap();
+        }
+
+        let (first, mut next) = current.get_mut();
+
+        for _ in i + 1..j {
+            next = next.unwrap().get_next_mut();
+        }
+
+        core::mem::swap(first, next.unwrap().get_value_mut());
+    }
+
+    /// Rotate the linked list `n` places to the left, so the value at index
+    /// `n` becomes the first.
+    ///
+    /// Panics if `n` is greater than the length of the linked list.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.size,
+            "rotation amount {} exceeds the len {}",
+            n,
+            self.size
+        );
+
+        if n == 0 || n == self.size {
+            return;
+        }
+
+        let mut current = self.head.as_mut().unwrap();
+
+        for _ in 0..n - 1 {
+            current = current.get_next_mut().unwrap();
+        }
+
+        let mut rest = current.take_next().unwrap();
+        let front = self.head.take().unwrap();
+        let mut current = &mut rest;
+
+        while current.has_next() {
+            current = current.get_next_mut().unwrap();
+        }
+
+        current.set_next(front, self.alloc.clone());
+        self.head = Some(rest);
+    }
+
+    /// Rotate the linked list `n` places to the right, so the value at index
+    /// `len - n` becomes the first.
+    ///
+    /// Panics if `n` is greater than the length of the linked list.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.size,
+            "rotation amount {} exceeds the len {}",
+            n,
+            self.size
+        );
+
+        self.rotate_left(self.size - n);
+    }
+
     /// Reverse the elements in the linked list in place.
     pub fn reverse(&mut self) {
         let mut orig = Self {
             head: self.head.take(),
             size: self.size,
+            alloc: self.alloc.clone(),
         };
         self.size = 0;
 
-        while orig.len() > 0 {
+        while !orig.is_empty() {
             let value = orig.pop_front().unwrap();
             self.push(0, value).unwrap();
         }
     }
 
+    /// Resolve range bounds against the linked list into start and end
+    /// indices.
+    fn range_indices<R: RangeBounds<usize>>(&self, range: R) -> Result<(usize, usize)> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => Some(self.size),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= self.size => Ok((start, end)),
+            (start, end) => Err(LinkedListError::InvalidRange {
+                start: start.unwrap_or(usize::MAX),
+                end: end.unwrap_or(usize::MAX),
+                size: self.size,
+            }),
+        }
+    }
+
+    /// Returns a view of a range of the linked list. The range is given as
+    /// for slices, and must not extend past the end of the linked list.
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Result<Range<'_, T, A>> {
+        let (start, end) = self.range_indices(range)?;
+        let mut first_node = self.head.as_ref();
+
+        for _ in 0..start {
+            first_node = first_node.and_then(|node| node.get_next());
+        }
+
+        Ok(Range {
+            first_node,
+            size: end - start,
+        })
+    }
+
+    /// Returns a mutable view of a range of the linked list. The range is
+    /// given as for slices, and must not extend past the end of the linked
+    /// list.
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Result<RangeMut<'_, T, A>> {
+        let (start, end) = self.range_indices(range)?;
+        let mut first_node = self.head.as_mut();
+
+        for _ in 0..start {
+            first_node = first_node.and_then(|node| node.get_next_mut());
+        }
+
+        Ok(RangeMut {
+            first_node,
+            size: end - start,
+        })
+    }
+
+    /// Split the linked list at `mid` into two mutable iterators over the
+    /// prefix and suffix, which can be used independently, e.g. from
+    /// different threads. No nodes are moved.
+    ///
+    /// Each node owns the rest of the chain, so the prefix's nodes cannot be
+    /// borrowed separately from the suffix without unsafe code. The prefix
+    /// iterator therefore allocates a buffer holding references to its `mid`
+    /// values, while the suffix iterator walks its nodes without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[track_caller]
+    pub fn split_at_mut(&mut self, mid: usize) -> (IterMut<'_, T, A>, IterMut<'_, T, A>) {
+        assert!(mid <= self.size, "mid > len");
+
+        let mut prefix = VecDeque::with_capacity(mid);
+        let mut current = self.head.as_mut();
+
+        for _ in 0..mid {
+            let (value, next) = current.unwrap().get_mut();
+            prefix.push_back(value);
+            current = next;
+        }
+
+        (
+            IterMut {
+                current_node: None,
+                remaining: mid,
+                buffer: Some(prefix),
+            },
+            IterMut {
+                current_node: current,
+                remaining: self.size - mid,
+                buffer: None,
+            },
+        )
+    }
+
     /// Returns an iterator over the elements in the linked list.
-    pub fn iter<'a>(&'a self) -> Iter<'a, T> {
+    pub fn iter<'a>(&'a self) -> Iter<'a, T, A> {
         Iter::new(self)
     }
 
     /// Returns an iterator over the elements in the linked list, allowing for them to be mutated.
-    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, T> {
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, T, A> {
         IterMut::new(self)
     }
 }
 
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Sort the nodes after the first with `sort`, then move the first value
+    /// forward into place. It only passes values that compare less, so the
+    /// sort stays stable.
+    fn sort_rest<F, S>(&mut self, compare: &mut F, sort: S)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        S: FnOnce(Link<T, A>, usize, &mut F) -> Link<T, A>,
+    {
+        let Some(head) = self.head.as_mut() else {
+            return;
+        };
+
+        head.next = sort(head.next.take(), self.size - 1, compare);
+        let mut current = head;
+
+        while let Some(next) = current
+            .next
+            .as_deref_mut()
+            .filter(|next| compare(&next.value, &current.value) == Ordering::Less)
+        {
+            core::mem::swap(&mut current.value, &mut next.value);
+            current = next;
+        }
+    }
+
+    /// Stably sort the linked list with a comparator function, relinking
+    /// nodes rather than allocating.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.sort_rest(&mut compare, sort_link);
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> LinkedList<T, A> {
+    /// Stably sort the linked list, relinking nodes rather than allocating.
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send, A: Allocator + Clone + Send> LinkedList<T, A> {
+    /// Stably sort the linked list with a comparator function, sorting
+    /// halves of the node chain on separate threads and merging them by
+    /// relinking nodes.
+    pub fn par_sort_by<F: Fn(&T, &T) -> Ordering + Sync>(&mut self, compare: F) {
+        let depth = parallel_sort_depth();
+        self.sort_rest(&mut &compare, |link, len, compare| {
+            par_sort_link(link, len, depth, *compare)
+        });
+    }
+
+    /// Stably sort the linked list, sorting halves of the node chain on
+    /// separate threads and merging them by relinking nodes.
+    pub fn par_sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sort_by(T::cmp);
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> LinkedList<T, A> {
+    /// Combine two sorted linked lists by relinking their nodes. A node is
+    /// only allocated when the first nodes of both lists are kept, as one of
+    /// them can no longer be inline.
+    fn combine(self, other: Self, operation: SetOperation) -> Self {
+        combine_chains(
+            Chain::new(self.head),
+            Chain::new(other.head),
+            operation,
+            self.alloc,
+        )
+    }
+
+    /// Merge two sorted linked lists into one sorted linked list, keeping
+    /// every value. On ties, values from `self` come first.
+    pub fn merge(self, other: Self) -> Self {
+        self.combine(other, SetOperation::Merge)
+    }
+
+    /// Get the union of two sorted linked lists. Each value in `self` is
+    /// matched with at most one equal value in `other`, which is dropped.
+    pub fn union(self, other: Self) -> Self {
+        self.combine(other, SetOperation::Union)
+    }
+
+    /// Get the values of a sorted linked list that are matched by an equal
+    /// value in another sorted linked list.
+    pub fn intersection(self, other: Self) -> Self {
+        self.combine(other, SetOperation::Intersection)
+    }
+
+    /// Get the values of a sorted linked list that are not matched by an
+    /// equal value in another sorted linked list.
+    pub fn difference(self, other: Self) -> Self {
+        self.combine(other, SetOperation::Difference)
+    }
+
+    /// Get the values of two sorted linked lists that are not matched by an
+    /// equal value in the other, in sorted order.
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        self.combine(other, SetOperation::SymmetricDifference)
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> LinkedList<T, A> {
+    /// Merge any number of sorted linked lists into one sorted linked list
+    /// by relinking their nodes, keeping a binary heap of the lists' first
+    /// nodes. On ties, values from earlier lists come first. Only the inline
+    /// first nodes of all but one list need new nodes.
+    pub fn merge_all<I: IntoIterator<Item = Self>>(lists: I) -> Self
+    where
+        A: Default,
+    {
+        Self::merge_all_in(lists, A::default())
+    }
+
+    /// Merge any number of sorted linked lists like `merge_all`, allocating
+    /// the merged list's new nodes with `alloc`.
+    pub fn merge_all_in<I: IntoIterator<Item = Self>>(lists: I, alloc: A) -> Self {
+        let mut heads: BinaryHeap<_> = lists
+            .into_iter()
+            .filter_map(|list| list.head)
+            .enumerate()
+            .map(|(list, head)| MergeHead {
+                chain: Chain::new(Some(head)),
+                list,
+            })
+            .collect();
+        let mut merged = ChainBuilder::new(alloc);
+        let mut rest = None;
+        let mut tail = &mut rest;
+
+        while let Some(mut head) = heads.pop() {
+            let node = head.chain.pop().unwrap();
+            tail = merged.append(tail, node);
+
+            if head.chain.peek().is_some() {
+                heads.push(head);
+            }
+        }
+
+        merged.finish(rest)
+    }
+}
+
+impl<T: PartialEq, A: Allocator + Clone> LinkedList<T, A> {
+    /// Check if the linked list contains a value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|x| x == value)
+    }
+
+    /// Remove consecutive equal values, keeping the first of each run.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> LinkedList<T, A> {
+    /// Resize the linked list to `len` nodes, filling new nodes at the end
+    /// with copies of `value`.
+    pub fn resize(&mut self, len: usize, value: T) {
+        self.resize_with(len, || value.clone());
+    }
+}
+
 impl<T> Default for LinkedList<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T> Index<usize> for LinkedList<T> {
+impl<T, A: Allocator + Clone> Index<usize> for LinkedList<T, A> {
     type Output = T;
 
+    #[track_caller]
     fn index(&self, index: usize) -> &Self::Output {
-        self.get_value(index).unwrap()
+        let len = self.len();
+        self.get_value(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
     }
 }
 
-impl<T> IndexMut<usize> for LinkedList<T> {
+impl<T, A: Allocator + Clone> IndexMut<usize> for LinkedList<T, A> {
+    #[track_caller]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_value_mut(index).unwrap()
+        let len = self.len();
+        self.get_value_mut(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
     }
 }
 
@@ -455,8 +1568,13 @@ impl<T: Clone, const N: usize> From<&mut [T; N]> for LinkedList<T> {
 
 impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
     fn from(arr: [T; N]) -> Self {
-        let vector: Vec<_> = arr.into();
-        vector.into()
+        let mut ll = Self::new();
+
+        for value in arr.into_iter().rev() {
+            ll.push_front(value);
+        }
+
+        ll
     }
 }
 
@@ -472,7 +1590,9 @@ impl<T> From<Vec<T>> for LinkedList<T> {
     }
 }
 
-impl<T: std::fmt::Debug, const N: usize> TryInto<[T; N]> for LinkedList<T> {
+impl<T: core::fmt::Debug, A: Allocator + Clone, const N: usize> TryInto<[T; N]>
+    for LinkedList<T, A>
+{
     type Error = LinkedListError;
 
     fn try_into(self) -> core::result::Result<[T; N], Self::Error> {
@@ -488,52 +1608,173 @@ impl<T: std::fmt::Debug, const N: usize> TryInto<[T; N]> for LinkedList<T> {
     }
 }
 
-impl<T> Into<Vec<T>> for LinkedList<T> {
-    fn into(mut self) -> Vec<T> {
-        let mut vector = Vec::new();
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for Vec<T> {
+    fn from(mut ll: LinkedList<T, A>) -> Self {
+        let mut vector = Vec::with_capacity(ll.size);
 
-        while self.size > 0 {
-            vector.push(self.pop_front().unwrap());
+        while ll.size > 0 {
+            vector.push(ll.pop_front().unwrap());
         }
 
         vector
     }
 }
 
+impl<T> From<VecDeque<T>> for LinkedList<T> {
+    fn from(mut deque: VecDeque<T>) -> Self {
+        let mut ll = Self::new();
+
+        while let Some(value) = deque.pop_back() {
+            ll.push_front(value);
+        }
+
+        ll
+    }
+}
+
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for VecDeque<T> {
+    fn from(mut ll: LinkedList<T, A>) -> Self {
+        let mut deque = VecDeque::with_capacity(ll.size);
+
+        while ll.size > 0 {
+            deque.push_back(ll.pop_front().unwrap());
+        }
+
+        deque
+    }
+}
+
+impl<T> From<alloc::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(mut std_ll: alloc::collections::LinkedList<T>) -> Self {
+        let mut ll = Self::new();
+
+        while let Some(value) = std_ll.pop_back() {
+            ll.push_front(value);
+        }
+
+        ll
+    }
+}
+
+impl<T, A: Allocator + Clone> From<LinkedList<T, A>> for alloc::collections::LinkedList<T> {
+    fn from(mut ll: LinkedList<T, A>) -> Self {
+        let mut std_ll = Self::new();
+
+        while ll.size > 0 {
+            std_ll.push_back(ll.pop_front().unwrap());
+        }
+
+        std_ll
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<doublylinkedlist::LinkedList<T>> for LinkedList<T> {
+    fn from(mut dll: doublylinkedlist::LinkedList<T>) -> Self {
+        let mut ll = Self::new();
+
+        while let Ok(value) = dll.pop_back() {
+            ll.push_front(value);
+        }
+
+        ll
+    }
+}
+
 impl<T> FromIterator<T> for LinkedList<T> {
     fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
-        Vec::from_iter(iter).into()
+        let mut ll = Self::new();
+        ll.push_back_all(iter);
+        ll
     }
 }
 
-impl<T> IntoIterator for LinkedList<T> {
+impl<T, A: Allocator + Clone> IntoIterator for LinkedList<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let vector: Vec<_> = self.into();
-        vector.into_iter()
+        IntoIter(self)
     }
 }
 
-impl<T: PartialEq> PartialEq for LinkedList<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.size == other.size {
-            let mut self_current = self.head.as_ref();
-            let mut other_current = other.head.as_ref();
+impl<T: PartialEq<U>, U, A: Allocator + Clone, B: Allocator + Clone> PartialEq<LinkedList<U, B>>
+    for LinkedList<T, A>
+{
+    fn eq(&self, other: &LinkedList<U, B>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
 
-            while self_current.is_some() {
-                if self_current.unwrap().value != other_current.unwrap().value {
-                    return false;
-                }
+#[cfg(feature = "std")]
+impl<T: PartialEq<U>, U, A: Allocator + Clone, B: Allocator + Clone>
+    PartialEq<doublylinkedlist::LinkedList<U, B>> for LinkedList<T, A>
+{
+    fn eq(&self, other: &doublylinkedlist::LinkedList<U, B>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
 
-                self_current = self_current.unwrap().get_next();
-                other_current = other_current.unwrap().get_next();
-            }
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<[U]> for LinkedList<T, A> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
 
-            true
-        } else {
-            false
-        }
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<&[U]> for LinkedList<T, A> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, const N: usize> PartialEq<[U; N]>
+    for LinkedList<T, A>
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<Vec<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<VecDeque<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for [T] {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for &[T] {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone, const N: usize> PartialEq<LinkedList<U, A>>
+    for [T; N]
+{
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for Vec<T> {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator + Clone> PartialEq<LinkedList<U, A>> for VecDeque<T> {
+    fn eq(&self, other: &LinkedList<U, A>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
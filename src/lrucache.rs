@@ -0,0 +1,189 @@
+use crate::doublylinkedlist::{self, LinkedList, NodeHandle};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cache that evicts the least recently used entry once it is full.
+pub struct LruCache<K, V> {
+    /// The cached entries, each with a handle to its key's node in `order`.
+    map: HashMap<K, (V, NodeHandle<K>)>,
+    /// The keys in recency order, most recently used first.
+    order: LinkedList<K>,
+    /// The maximum number of entries.
+    capacity: usize,
+}
+
+impl<K: Clone + Hash + Eq, V> LruCache<K, V> {
+    /// Create an empty cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            order: LinkedList::new(),
+            capacity,
+        }
+    }
+
+    /// Get the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Get the maximum number of entries in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get a reference to a value, marking it as most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (value, handle) = self.map.get(key)?;
+        self.order.move_handle_to_front(handle).unwrap();
+
+        Some(value)
+    }
+
+    /// Get a mutable reference to a value, marking it as most recently used.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (value, handle) = self.map.get_mut(key)?;
+        self.order.move_handle_to_front(handle).unwrap();
+
+        Some(value)
+    }
+
+    /// Get a reference to a value without changing its recency.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|(value, _)| value)
+    }
+
+    /// Get the least recently used entry without changing its recency.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        let key = self.order.back()?;
+        let (key, (value, _)) = self.map.get_key_value(key)?;
+
+        Some((key, value))
+    }
+
+    /// Check if the cache contains a key, without changing its recency.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Insert an entry, marking it as most recently used. If the key was
+    /// already present, its old entry is returned. Otherwise, if the cache was
+    /// full, the evicted least recently used entry is returned.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some((old_value, handle)) = self.map.get_mut(&key) {
+            self.order.move_handle_to_front(handle).unwrap();
+            let old_value = std::mem::replace(old_value, value);
+
+            return Some((key, old_value));
+        }
+
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+
+        let evicted = if self.map.len() >= self.capacity {
+            self.pop_lru()
+        } else {
+            None
+        };
+
+        let handle = self.order.push_front_handle(key.clone());
+        self.map.insert(key, (value, handle));
+
+        evicted
+    }
+
+    /// Remove an entry, returning its value.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (value, handle) = self.map.remove(key)?;
+        self.order.remove_handle(&handle).unwrap();
+
+        Some(value)
+    }
+
+    /// Remove the least recently used entry, returning it.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let key = self.order.pop_back().ok()?;
+        let (value, _) = self.map.remove(&key)?;
+
+        Some((key, value))
+    }
+
+    /// Change the capacity of the cache, evicting the least recently used
+    /// entries if it holds more than the new capacity.
+    pub fn resize(&mut self, capacity: usize) {
+        while self.map.len() > capacity {
+            self.pop_lru();
+        }
+
+        self.capacity = capacity;
+    }
+
+    /// Clear the cache.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    /// Returns an iterator over the entries, most recently used first.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            keys: self.order.iter(),
+            map: &self.map,
+        }
+    }
+}
+
+/// An iterator over the entries of an LRU cache, most recently used first.
+pub struct Iter<'a, K, V> {
+    /// The keys in recency order.
+    keys: doublylinkedlist::Iter<'a, K>,
+    /// The cached entries.
+    map: &'a HashMap<K, (V, NodeHandle<K>)>,
+}
+
+impl<'a, K: Clone + Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let (key, (value, _)) = self.map.get_key_value(key)?;
+
+        Some((key, value))
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V> IntoIterator for &'a LruCache<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,97 @@
+// Rayon parallel iterators for the linked lists.
+//
+// Linked lists cannot be split in the middle without walking them, so, like
+// rayon's own support for `std::collections::LinkedList`, each list is first
+// walked once into a vector, which rayon then splits into chunks for its
+// work-stealing scheduler. Indexed iterators preserve the list order when
+// collecting.
+
+use crate::doublylinkedlist;
+use crate::linkedlist;
+use allocator_api2::alloc::Allocator;
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend};
+use rayon::vec;
+
+impl<'a, T: Sync, A: Allocator + Clone> IntoParallelIterator for &'a linkedlist::LinkedList<T, A> {
+    type Item = &'a T;
+    type Iter = vec::IntoIter<&'a T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Clone> IntoParallelIterator
+    for &'a mut linkedlist::LinkedList<T, A>
+{
+    type Item = &'a mut T;
+    type Iter = vec::IntoIter<&'a mut T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<T: Send, A: Allocator + Clone> IntoParallelIterator for linkedlist::LinkedList<T, A> {
+    type Item = T;
+    type Iter = vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for linkedlist::LinkedList<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        Vec::from_par_iter(par_iter).into()
+    }
+}
+
+impl<T: Send, A: Allocator + Clone> ParallelExtend<T> for linkedlist::LinkedList<T, A> {
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        self.push_back_all(Vec::from_par_iter(par_iter));
+    }
+}
+
+impl<'a, T: Sync, A: Allocator + Clone> IntoParallelIterator
+    for &'a doublylinkedlist::LinkedList<T, A>
+{
+    type Item = &'a T;
+    type Iter = vec::IntoIter<&'a T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Clone> IntoParallelIterator
+    for &'a mut doublylinkedlist::LinkedList<T, A>
+{
+    type Item = &'a mut T;
+    type Iter = vec::IntoIter<&'a mut T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<T: Send, A: Allocator + Clone> IntoParallelIterator for doublylinkedlist::LinkedList<T, A> {
+    type Item = T;
+    type Iter = vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for doublylinkedlist::LinkedList<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        Vec::from_par_iter(par_iter).into()
+    }
+}
+
+impl<T: Send, A: Allocator + Clone> ParallelExtend<T> for doublylinkedlist::LinkedList<T, A> {
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        self.extend(Vec::from_par_iter(par_iter));
+    }
+}
@@ -0,0 +1,367 @@
+#![allow(dead_code)]
+
+use crate::arena::{self, Arena, Slot};
+use crate::linkedlist::{index_out_of_bounds, LinkedListError, Result};
+use alloc::vec::Vec;
+use allocator_api2::vec;
+use core::iter::{FromIterator, IntoIterator};
+use core::ops::{Index, IndexMut};
+
+/// An iterator over the elements of a pooled linked list.
+pub struct Iter<'a, T>(arena::Iter<'a, vec::Vec<Slot<T>>>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over the elements of a pooled linked list, allowing for them to be mutated.
+pub struct IterMut<'a, T>(arena::IterMut<'a, T>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// An iterator that moves the elements out of a pooled linked list.
+pub struct IntoIter<T>(PooledLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back().ok()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// A doubly linked list that stores its nodes in a pool of reusable slots.
+/// Removed nodes return their slot to the pool, so once the pool is large
+/// enough, pushing and popping do not allocate.
+#[derive(Clone)]
+pub struct PooledLinkedList<T> {
+    /// The pool of node slots.
+    arena: Arena<vec::Vec<Slot<T>>>,
+}
+
+impl<T> PooledLinkedList<T> {
+    /// Create an empty linked list.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty linked list that can hold at least `capacity` nodes
+    /// without allocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            arena: Arena::new(vec::Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Get the number of nodes in the linked list.
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Check if the linked list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.arena.len() == 0
+    }
+
+    /// Get the number of nodes the linked list can hold without allocating.
+    pub fn capacity(&self) -> usize {
+        self.arena.capacity()
+    }
+
+    /// Reserve space for at least `additional` more nodes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.arena.reserve(additional);
+    }
+
+    /// Release all pooled slots, moving the nodes into list order and
+    /// shrinking the pool to fit them.
+    pub fn shrink_to_fit(&mut self) {
+        self.arena.shrink_to_fit();
+    }
+
+    /// Get the slot holding the node at a given index.
+    fn slot_at(&self, index: usize) -> Result<usize> {
+        self.arena
+            .slot_at(index)
+            .ok_or(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.len(),
+            })
+    }
+
+    /// Get a reference to the first value.
+    pub fn front(&self) -> Option<&T> {
+        self.arena.head().map(|head| &self.arena.node(head).value)
+    }
+
+    /// Get a mutable reference to the first value.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let head = self.arena.head()?;
+
+        Some(&mut self.arena.node_mut(head).value)
+    }
+
+    /// Get a reference to the last value.
+    pub fn back(&self) -> Option<&T> {
+        self.arena.tail().map(|tail| &self.arena.node(tail).value)
+    }
+
+    /// Get a mutable reference to the last value.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let tail = self.arena.tail()?;
+
+        Some(&mut self.arena.node_mut(tail).value)
+    }
+
+    /// Get a reference to the value at a node by index.
+    pub fn get_value(&self, index: usize) -> Result<&T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(&self.arena.node(slot).value)
+    }
+
+    /// Get a mutable reference to the value at a node by index.
+    pub fn get_value_mut(&mut self, index: usize) -> Result<&mut T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(&mut self.arena.node_mut(slot).value)
+    }
+
+    /// Set the value at a node by index.
+    pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
+        *self.get_value_mut(index)? = value;
+
+        Ok(())
+    }
+
+    /// Insert a value in a new node at a given index in the linked list.
+    pub fn push(&mut self, index: usize, value: T) -> Result<()> {
+        if index == self.len() {
+            self.push_back(value);
+        } else {
+            let next = self.slot_at(index)?;
+            let prev = self.arena.node(next).prev;
+            self.arena.link(value, prev, Some(next));
+        }
+
+        Ok(())
+    }
+
+    /// Insert a value at the start of the linked list.
+    pub fn push_front(&mut self, value: T) {
+        self.arena.link(value, None, self.arena.head());
+    }
+
+    /// Insert a value at the end of the linked list.
+    pub fn push_back(&mut self, value: T) {
+        self.arena.link(value, self.arena.tail(), None);
+    }
+
+    /// Remove the node at a given index, returning the node's owned value.
+    pub fn pop(&mut self, index: usize) -> Result<T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(self.arena.unlink(slot))
+    }
+
+    /// Remove the first node, returning the node's owned value.
+    pub fn pop_front(&mut self) -> Result<T> {
+        match self.arena.head() {
+            Some(head) => Ok(self.arena.unlink(head)),
+            None => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+        }
+    }
+
+    /// Remove the last node, returning the node's owned value.
+    pub fn pop_back(&mut self) -> Result<T> {
+        match self.arena.tail() {
+            Some(tail) => Ok(self.arena.unlink(tail)),
+            None => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+        }
+    }
+
+    /// Clear the linked list, keeping its slots pooled for reuse.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+    }
+
+    /// Reverse the elements in the linked list in place.
+    pub fn reverse(&mut self) {
+        self.arena.reverse();
+    }
+
+    /// Returns an iterator over the elements in the linked list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.arena.iter())
+    }
+
+    /// Returns an iterator over the elements in the linked list, allowing
+    /// for them to be mutated. The nodes are first moved into list order in
+    /// the pool, so that the iterator can walk the slots directly.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut(self.arena.iter_mut())
+    }
+}
+
+impl<T> Default for PooledLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for PooledLinkedList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for PooledLinkedList<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get_value(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T> IndexMut<usize> for PooledLinkedList<T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_value_mut(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T: Clone> From<&[T]> for PooledLinkedList<T> {
+    fn from(arr: &[T]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PooledLinkedList<T> {
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for PooledLinkedList<T> {
+    fn from(vector: Vec<T>) -> Self {
+        vector.into_iter().collect()
+    }
+}
+
+impl<T> From<PooledLinkedList<T>> for Vec<T> {
+    fn from(ll: PooledLinkedList<T>) -> Self {
+        ll.into_iter().collect()
+    }
+}
+
+impl<T> Extend<T> for PooledLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for PooledLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ll = Self::new();
+        ll.extend(iter);
+        ll
+    }
+}
+
+impl<T> IntoIterator for PooledLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PooledLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<PooledLinkedList<U>> for PooledLinkedList<T> {
+    fn eq(&self, other: &PooledLinkedList<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for PooledLinkedList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for PooledLinkedList<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.len() == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for PooledLinkedList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
@@ -0,0 +1,607 @@
+#![allow(dead_code)]
+
+use crate::linkedlist::{index_out_of_bounds, LinkedListError, Result};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::{FromIterator, IntoIterator};
+use core::ops::{Index, IndexMut};
+
+/// The maximum number of levels in a skip list.
+const MAX_LEVEL: usize = 32;
+
+/// The seed for the skip list's level generator.
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// An iterator over the elements of a skip list.
+pub struct Iter<'a, T> {
+    /// The skip list being iterated over.
+    sl: &'a SkipList<T>,
+    /// The index of the current node in the node arena.
+    current_node: Option<usize>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Create an iterator from a skip list.
+    pub fn new(sl: &'a SkipList<T>) -> Self {
+        Self {
+            sl,
+            current_node: sl.head[0].next,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.sl.node(self.current_node?);
+        self.current_node = node.links[0].next;
+
+        Some(&node.value)
+    }
+}
+
+/// An iterator over the elements of a skip list, allowing for them to be mutated.
+pub struct IterMut<'a, T>(core::slice::IterMut<'a, Option<SkipListNode<T>>>);
+
+impl<'a, T> IterMut<'a, T> {
+    /// Create a mutable iterator from a skip list, first moving its nodes
+    /// into list order in the arena.
+    pub fn new(sl: &'a mut SkipList<T>) -> Self {
+        sl.compact();
+
+        Self(sl.nodes.iter_mut())
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.0.next()?.as_mut().unwrap();
+
+        Some(&mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An iterator that moves the elements out of a skip list.
+pub struct IntoIter<T>(SkipList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front().ok()
+    }
+}
+
+/// A forward link from a node at one level of a skip list.
+#[derive(Clone, Copy, Debug)]
+struct Link {
+    /// The index of the next node at this level in the node arena.
+    next: Option<usize>,
+    /// The number of positions between this node and the next node at this
+    /// level. If there is no next node, this is the distance to one position
+    /// past the end of the list.
+    span: usize,
+}
+
+/// A node in a skip list.
+#[derive(Clone, Debug)]
+struct SkipListNode<T> {
+    /// The node's value.
+    value: T,
+    /// The node's forward links, one per level.
+    links: Vec<Link>,
+}
+
+/// An indexable skip list, providing expected O(log n) positional access,
+/// insertion and removal.
+#[derive(Clone, Debug)]
+pub struct SkipList<T> {
+    /// The arena holding every node in the skip list.
+    nodes: Vec<Option<SkipListNode<T>>>,
+    /// Indices of unused slots in the node arena.
+    free: Vec<usize>,
+    /// The head's forward links, one per level.
+    head: Vec<Link>,
+    /// The total number of nodes in the skip list.
+    size: usize,
+    /// The state of the level generator.
+    rng: u64,
+}
+
+impl<T> SkipList<T> {
+    /// Create an empty skip list.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: vec![Link {
+                next: None,
+                span: 1,
+            }],
+            size: 0,
+            rng: SEED,
+        }
+    }
+
+    /// Get the number of nodes in the skip list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Check if the skip list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get a reference to a node in the arena.
+    fn node(&self, index: usize) -> &SkipListNode<T> {
+        self.nodes[index].as_ref().unwrap()
+    }
+
+    /// Get a mutable reference to a node in the arena.
+    fn node_mut(&mut self, index: usize) -> &mut SkipListNode<T> {
+        self.nodes[index].as_mut().unwrap()
+    }
+
+    /// Get the forward link at a level from a node, or from the head if no
+    /// node is given.
+    fn link(&self, node: Option<usize>, level: usize) -> Link {
+        match node {
+            Some(index) => self.node(index).links[level],
+            None => self.head[level],
+        }
+    }
+
+    /// Get a mutable reference to the forward link at a level from a node, or
+    /// from the head if no node is given.
+    fn link_mut(&mut self, node: Option<usize>, level: usize) -> &mut Link {
+        match node {
+            Some(index) => &mut self.node_mut(index).links[level],
+            None => &mut self.head[level],
+        }
+    }
+
+    /// Returns an iterator over the arena indices of the nodes, in order.
+    fn node_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.head[0].next, |&index| self.node(index).links[0].next)
+    }
+
+    /// Move the nodes into list order at the start of the arena, so that they
+    /// can be walked without following links, and drop the unused slots.
+    /// Takes linear time and does not allocate.
+    fn compact(&mut self) {
+        // Replace each node's bottom link with its index in the list, remap
+        // the upper links to those indices, then swap every node into the
+        // slot at its index.
+        let mut current = self.head[0].next;
+        let mut rank = 0;
+
+        while let Some(index) = current {
+            current = self.node_mut(index).links[0].next.replace(rank);
+            rank += 1;
+        }
+
+        let rank = |nodes: &[Option<SkipListNode<T>>], index: usize| {
+            nodes[index].as_ref().unwrap().links[0].next
+        };
+
+        for level in 1..self.head.len() {
+            if let Some(next) = self.head[level].next {
+                self.head[level].next = rank(&self.nodes, next);
+            }
+        }
+
+        for index in 0..self.nodes.len() {
+            let levels = self.nodes[index]
+                .as_ref()
+                .map_or(0, |node| node.links.len());
+
+            for level in 1..levels {
+                if let Some(next) = self.node(index).links[level].next {
+                    self.node_mut(index).links[level].next = rank(&self.nodes, next);
+                }
+            }
+        }
+
+        for index in 0..self.nodes.len() {
+            while let Some(Some(rank)) = self.nodes[index].as_ref().map(|node| node.links[0].next) {
+                if rank == index {
+                    break;
+                }
+
+                self.nodes.swap(index, rank);
+            }
+        }
+
+        self.nodes.truncate(self.size);
+        self.free.clear();
+        self.head[0].next = (self.size > 0).then_some(0);
+
+        for (index, node) in self.nodes.iter_mut().enumerate() {
+            let next = index + 1;
+            node.as_mut().unwrap().links[0].next = (next < self.size).then_some(next);
+        }
+    }
+
+    /// Generate a random level for a new node.
+    fn random_level(&mut self) -> usize {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+
+        (self.rng.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+
+    /// Find the arena index of the node at an index. The index must be in bounds.
+    fn find(&self, index: usize) -> usize {
+        let rank = index + 1;
+        let mut position = 0;
+        let mut current = None;
+
+        for level in (0..self.head.len()).rev() {
+            loop {
+                let link = self.link(current, level);
+
+                match link.next {
+                    Some(next) if position + link.span <= rank => {
+                        position += link.span;
+                        current = Some(next);
+                    }
+                    _ => break,
+                }
+            }
+
+            if position == rank {
+                break;
+            }
+        }
+
+        current.unwrap()
+    }
+
+    /// Find the last node before an index at every level, along with each
+    /// node's position (the head being at position 0).
+    fn predecessors(&self, index: usize) -> Vec<(Option<usize>, usize)> {
+        let mut update = vec![(None, 0); self.head.len()];
+        let mut position = 0;
+        let mut current = None;
+
+        for level in (0..self.head.len()).rev() {
+            loop {
+                let link = self.link(current, level);
+
+                match link.next {
+                    Some(next) if position + link.span <= index => {
+                        position += link.span;
+                        current = Some(next);
+                    }
+                    _ => break,
+                }
+            }
+
+            update[level] = (current, position);
+        }
+
+        update
+    }
+
+    /// Get a reference to the value at a node by index.
+    pub fn get_value(&self, index: usize) -> Result<&T> {
+        if index < self.size {
+            Ok(&self.node(self.find(index)).value)
+        } else {
+            Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            })
+        }
+    }
+
+    /// Get a mutable reference to the value at a node by index.
+    pub fn get_value_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index < self.size {
+            let node = self.find(index);
+
+            Ok(&mut self.node_mut(node).value)
+        } else {
+            Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            })
+        }
+    }
+
+    /// Set the value at a node by index.
+    pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
+        *self.get_value_mut(index)? = value;
+
+        Ok(())
+    }
+
+    /// Insert a value in a new node at a given index in the skip list.
+    pub fn push(&mut self, index: usize, value: T) -> Result<()> {
+        if index > self.size {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            });
+        }
+
+        let level = self.random_level();
+
+        while self.head.len() < level {
+            self.head.push(Link {
+                next: None,
+                span: self.size + 1,
+            });
+        }
+
+        let update = self.predecessors(index);
+        let mut links = Vec::with_capacity(level);
+
+        for (l, &(prev, position)) in update.iter().enumerate().take(level) {
+            let prev_link = self.link(prev, l);
+            links.push(Link {
+                next: prev_link.next,
+                span: prev_link.span - (index - position),
+            });
+        }
+
+        let new_node = SkipListNode { value, links };
+        let new_index = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = Some(new_node);
+                slot
+            }
+            None => {
+                self.nodes.push(Some(new_node));
+                self.nodes.len() - 1
+            }
+        };
+
+        for (l, &(prev, position)) in update.iter().enumerate() {
+            let prev_link = self.link_mut(prev, l);
+
+            if l < level {
+                prev_link.next = Some(new_index);
+                prev_link.span = index + 1 - position;
+            } else {
+                prev_link.span += 1;
+            }
+        }
+
+        self.size += 1;
+
+        Ok(())
+    }
+
+    /// Insert a value at the start of the skip list.
+    pub fn push_front(&mut self, value: T) {
+        self.push(0, value).unwrap();
+    }
+
+    /// Insert a value at the end of the skip list.
+    pub fn push_back(&mut self, value: T) {
+        self.push(self.size, value).unwrap();
+    }
+
+    /// Remove the node at a given index, returning the node's owned value.
+    pub fn pop(&mut self, index: usize) -> Result<T> {
+        if index >= self.size {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            });
+        }
+
+        let update = self.predecessors(index);
+        let target = self.link(update[0].0, 0).next.unwrap();
+        let node = self.nodes[target].take().unwrap();
+
+        for (l, &(prev, _)) in update.iter().enumerate() {
+            let prev_link = self.link_mut(prev, l);
+
+            if prev_link.next == Some(target) {
+                prev_link.next = node.links[l].next;
+                prev_link.span += node.links[l].span - 1;
+            } else {
+                prev_link.span -= 1;
+            }
+        }
+
+        while self.head.len() > 1 && self.head.last().unwrap().next.is_none() {
+            self.head.pop();
+        }
+
+        self.free.push(target);
+        self.size -= 1;
+
+        if self.size == 0 {
+            self.nodes.clear();
+            self.free.clear();
+        }
+
+        Ok(node.value)
+    }
+
+    /// Remove the first node, returning the node's owned value.
+    pub fn pop_front(&mut self) -> Result<T> {
+        self.pop(0)
+    }
+
+    /// Remove the last node, returning the node's owned value.
+    pub fn pop_back(&mut self) -> Result<T> {
+        match self.size {
+            0 => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+            size => self.pop(size - 1),
+        }
+    }
+
+    /// Clear the skip list.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.head = vec![Link {
+            next: None,
+            span: 1,
+        }];
+        self.size = 0;
+    }
+
+    /// Reverse the elements in the skip list.
+    pub fn reverse(&mut self) {
+        let values: Vec<_> = core::mem::take(self).into();
+        *self = values.into_iter().rev().collect();
+    }
+
+    /// Returns an iterator over the elements in the skip list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over the elements in the skip list, allowing for them to be mutated.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self)
+    }
+}
+
+impl<T> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for SkipList<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get_value(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T> IndexMut<usize> for SkipList<T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_value_mut(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T: Clone> From<&[T]> for SkipList<T> {
+    fn from(arr: &[T]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone> From<&mut [T]> for SkipList<T> {
+    fn from(arr: &mut [T]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for SkipList<T> {
+    fn from(arr: &[T; N]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone, const N: usize> From<&mut [T; N]> for SkipList<T> {
+    fn from(arr: &mut [T; N]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SkipList<T> {
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for SkipList<T> {
+    fn from(vector: Vec<T>) -> Self {
+        vector.into_iter().collect()
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> TryInto<[T; N]> for SkipList<T> {
+    type Error = LinkedListError;
+
+    fn try_into(self) -> core::result::Result<[T; N], Self::Error> {
+        if self.size == N {
+            let vector: Vec<_> = self.into();
+            Ok(vector.try_into().unwrap())
+        } else {
+            Err(LinkedListError::InvalidArraySize {
+                size: self.size,
+                array_size: N,
+            })
+        }
+    }
+}
+
+impl<T> From<SkipList<T>> for Vec<T> {
+    fn from(mut sl: SkipList<T>) -> Self {
+        let order: Vec<_> = sl.node_indices().collect();
+
+        order
+            .into_iter()
+            .map(|index| sl.nodes[index].take().unwrap().value)
+            .collect()
+    }
+}
+
+impl<T> FromIterator<T> for SkipList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut sl = Self::new();
+
+        for value in iter {
+            sl.push_back(value);
+        }
+
+        sl
+    }
+}
+
+impl<T> IntoIterator for SkipList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<SkipList<U>> for SkipList<T> {
+    fn eq(&self, other: &SkipList<U>) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for SkipList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.size == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for SkipList<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for SkipList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
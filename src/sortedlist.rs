@@ -0,0 +1,268 @@
+use crate::doublylinkedlist::{self, LinkedList};
+use std::cmp::Ordering;
+use std::iter::{FromIterator, IntoIterator};
+use std::ops::{Bound, RangeBounds};
+
+/// A comparison function used to order the values in a sorted list.
+pub trait Comparator<T> {
+    /// Compare two values.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// A comparator using the values' natural `Ord` ordering.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Natural;
+
+impl<T: Ord> Comparator<T> for Natural {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> Comparator<T> for F {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// A linked list that keeps its values sorted. Equal values are kept in
+/// insertion order.
+#[derive(Debug)]
+pub struct SortedList<T, C = Natural> {
+    /// The sorted values.
+    list: LinkedList<T>,
+    /// The comparator used to order the values.
+    comparator: C,
+}
+
+impl<T: Ord> SortedList<T> {
+    /// Create an empty sorted list using the values' natural ordering.
+    pub fn new() -> Self {
+        Self::with_comparator(Natural)
+    }
+}
+
+impl<T, C: Comparator<T>> SortedList<T, C> {
+    /// Create an empty sorted list using a custom comparator.
+    pub fn with_comparator(comparator: C) -> Self {
+        Self {
+            list: LinkedList::new(),
+            comparator,
+        }
+    }
+
+    /// Get the number of values in the sorted list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Check if the sorted list contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Insert a value after any values that compare equal to it, returning
+    /// the index at which it was inserted.
+    pub fn insert(&mut self, value: T) -> usize {
+        let comparator = &self.comparator;
+        self.list.insert_before_first(value, |other, value| {
+            comparator.compare(other, value) == Ordering::Greater
+        })
+    }
+
+    /// Get the index of the first value that compares equal to `value`,
+    /// stopping at the first value that compares greater.
+    fn find(&self, value: &T) -> Option<usize> {
+        let mut found = false;
+        let index = self
+            .list
+            .position(|other| match self.comparator.compare(other, value) {
+                Ordering::Less => false,
+                ordering => {
+                    found = ordering == Ordering::Equal;
+                    true
+                }
+            })?;
+
+        found.then_some(index)
+    }
+
+    /// Remove the first value that compares equal to `value`, returning it.
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let comparator = &self.comparator;
+        self.list
+            .remove_first_by(|other| comparator.compare(other, value))
+    }
+
+    /// Check if the sorted list contains a value that compares equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Get a reference to the smallest value.
+    pub fn first(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Get a reference to the largest value.
+    pub fn last(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Remove the smallest value, returning it.
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.list.pop_front().ok()
+    }
+
+    /// Remove the largest value, returning it.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.list.pop_back().ok()
+    }
+
+    /// Clear the sorted list.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    /// Merge another sorted list into this one in linear time by relinking
+    /// nodes. On ties, values already in this list come first.
+    pub fn merge(&mut self, other: Self) {
+        let comparator = &self.comparator;
+        self.list.merge_by(other.list, |a, b| {
+            comparator.compare(a, b) == Ordering::Less
+        });
+    }
+
+    /// Returns an iterator over the values in sorted order.
+    pub fn iter(&self) -> doublylinkedlist::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator over the values within a range, in sorted order.
+    /// The iterator stops at the first value past the end of the range.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T, C, R> {
+        Range {
+            values: self.list.iter(),
+            comparator: &self.comparator,
+            range,
+            started: false,
+            ended: false,
+        }
+    }
+}
+
+/// An iterator over the values of a sorted list within a range.
+pub struct Range<'a, T, C, R> {
+    /// The values not yet visited.
+    values: doublylinkedlist::Iter<'a, T>,
+    /// The comparator used to order the values.
+    comparator: &'a C,
+    /// The range of values to yield.
+    range: R,
+    /// Whether a value within the range has been reached.
+    started: bool,
+    /// Whether a value past the end of the range has been reached.
+    ended: bool,
+}
+
+impl<T, C: Comparator<T>, R: RangeBounds<T>> Range<'_, T, C, R> {
+    /// Check if a value is not before the start of the range.
+    fn after_start(&self, value: &T) -> bool {
+        match self.range.start_bound() {
+            Bound::Included(start) => self.comparator.compare(value, start) != Ordering::Less,
+            Bound::Excluded(start) => self.comparator.compare(value, start) == Ordering::Greater,
+            Bound::Unbounded => true,
+        }
+    }
+
+    /// Check if a value is not past the end of the range.
+    fn before_end(&self, value: &T) -> bool {
+        match self.range.end_bound() {
+            Bound::Included(end) => self.comparator.compare(value, end) != Ordering::Greater,
+            Bound::Excluded(end) => self.comparator.compare(value, end) == Ordering::Less,
+            Bound::Unbounded => true,
+        }
+    }
+}
+
+impl<'a, T, C: Comparator<T>, R: RangeBounds<T>> Iterator for Range<'a, T, C, R> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ended {
+            return None;
+        }
+
+        let value = loop {
+            let value = self.values.next()?;
+
+            if self.started || self.after_start(value) {
+                self.started = true;
+                break value;
+            }
+        };
+
+        self.ended = !self.before_end(value);
+
+        (!self.ended).then_some(value)
+    }
+}
+
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: Comparator<T>> Extend<T> for SortedList<T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut values = Vec::from_iter(iter);
+        values.sort();
+
+        Self {
+            list: values.into(),
+            comparator: Natural,
+        }
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedList<T> {
+    fn from(vector: Vec<T>) -> Self {
+        vector.into_iter().collect()
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for SortedList<T> {
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T, C> From<SortedList<T, C>> for LinkedList<T> {
+    fn from(sl: SortedList<T, C>) -> Self {
+        sl.list
+    }
+}
+
+impl<T, C> From<SortedList<T, C>> for Vec<T> {
+    fn from(sl: SortedList<T, C>) -> Self {
+        sl.list.into()
+    }
+}
+
+impl<T, C> IntoIterator for SortedList<T, C> {
+    type Item = T;
+    type IntoIter = doublylinkedlist::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
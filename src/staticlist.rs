@@ -0,0 +1,304 @@
+#![allow(dead_code)]
+
+use crate::arena::{self, Arena, Slot};
+use crate::linkedlist::{index_out_of_bounds, LinkedListError, Result, TryResult};
+use alloc::vec::Vec;
+use core::iter::IntoIterator;
+use core::ops::{Index, IndexMut};
+
+/// An iterator over the elements of a static linked list.
+pub struct Iter<'a, T, const N: usize>(arena::Iter<'a, [Slot<T>; N]>);
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An iterator over the elements of a static linked list, allowing for them to be mutated.
+pub struct IterMut<'a, T>(arena::IterMut<'a, T>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An owning iterator over the elements of a static linked list.
+pub struct IntoIter<T, const N: usize>(StaticLinkedList<T, N>);
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front().ok()
+    }
+}
+
+/// A linked list with a fixed capacity of `N` nodes, stored inline so that it
+/// never allocates.
+#[derive(Clone)]
+pub struct StaticLinkedList<T, const N: usize> {
+    /// The storage for the nodes.
+    arena: Arena<[Slot<T>; N]>,
+}
+
+impl<T, const N: usize> StaticLinkedList<T, N> {
+    /// Create an empty linked list.
+    pub fn new() -> Self {
+        Self {
+            arena: Arena::new(core::array::from_fn(|_| Slot::Free(None))),
+        }
+    }
+
+    /// Get the number of nodes in the linked list.
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Check if the linked list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.arena.len() == 0
+    }
+
+    /// Get the maximum number of nodes in the linked list.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Check if the linked list cannot hold any more nodes.
+    pub fn is_full(&self) -> bool {
+        self.arena.len() == N
+    }
+
+    /// Get the slot holding the node at a given index.
+    fn slot_at(&self, index: usize) -> Result<usize> {
+        self.arena
+            .slot_at(index)
+            .ok_or(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.len(),
+            })
+    }
+
+    /// Store a value in a new node linked between two nodes, handing the value
+    /// back if the linked list is full.
+    fn link(&mut self, value: T, prev: Option<usize>, next: Option<usize>) -> TryResult<T> {
+        match self.arena.try_link(value, prev, next) {
+            Ok(_) => Ok(()),
+            Err(value) => Err((LinkedListError::CapacityExceeded { capacity: N }, value)),
+        }
+    }
+
+    /// Get a reference to the value at a node by index.
+    pub fn get_value(&self, index: usize) -> Result<&T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(&self.arena.node(slot).value)
+    }
+
+    /// Get a mutable reference to the value at a node by index.
+    pub fn get_value_mut(&mut self, index: usize) -> Result<&mut T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(&mut self.arena.node_mut(slot).value)
+    }
+
+    /// Set the value at a node by index.
+    pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
+        *self.get_value_mut(index)? = value;
+
+        Ok(())
+    }
+
+    /// Insert a value in a new node at a given index in the linked list,
+    /// handing the value back on failure.
+    pub fn push(&mut self, index: usize, value: T) -> TryResult<T> {
+        if index == self.len() {
+            return self.push_back(value);
+        }
+
+        match self.slot_at(index) {
+            Ok(next) => {
+                let prev = self.arena.node(next).prev;
+                self.link(value, prev, Some(next))
+            }
+            Err(error) => Err((error, value)),
+        }
+    }
+
+    /// Insert a value at the start of the linked list, handing the value back
+    /// if the linked list is full.
+    pub fn push_front(&mut self, value: T) -> TryResult<T> {
+        self.link(value, None, self.arena.head())
+    }
+
+    /// Insert a value at the end of the linked list, handing the value back if
+    /// the linked list is full.
+    pub fn push_back(&mut self, value: T) -> TryResult<T> {
+        self.link(value, self.arena.tail(), None)
+    }
+
+    /// Remove the node at a given index, returning the node's owned value.
+    pub fn pop(&mut self, index: usize) -> Result<T> {
+        let slot = self.slot_at(index)?;
+
+        Ok(self.arena.unlink(slot))
+    }
+
+    /// Remove the first node, returning the node's owned value.
+    pub fn pop_front(&mut self) -> Result<T> {
+        match self.arena.head() {
+            Some(head) => Ok(self.arena.unlink(head)),
+            None => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+        }
+    }
+
+    /// Remove the last node, returning the node's owned value.
+    pub fn pop_back(&mut self) -> Result<T> {
+        match self.arena.tail() {
+            Some(tail) => Ok(self.arena.unlink(tail)),
+            None => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+        }
+    }
+
+    /// Clear the linked list.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+    }
+
+    /// Reverse the elements in the linked list in place.
+    pub fn reverse(&mut self) {
+        self.arena.reverse();
+    }
+
+    /// Returns an iterator over the elements in the linked list.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter(self.arena.iter())
+    }
+
+    /// Returns an iterator over the elements in the linked list, allowing for
+    /// them to be mutated. The nodes are first moved into list order in their
+    /// slots, so that the iterator can walk the slots directly.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut(self.arena.iter_mut())
+    }
+}
+
+impl<T, const N: usize> Default for StaticLinkedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for StaticLinkedList<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for StaticLinkedList<T, N> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get_value(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for StaticLinkedList<T, N> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_value_mut(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for StaticLinkedList<T, N> {
+    fn from(arr: [T; N]) -> Self {
+        let mut ll = Self::new();
+
+        for value in arr {
+            if ll.push_back(value).is_err() {
+                unreachable!("an array of N values fits in the linked list");
+            }
+        }
+
+        ll
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for StaticLinkedList<T, N> {
+    type Error = LinkedListError;
+
+    fn try_from(arr: &[T]) -> Result<Self> {
+        let mut ll = Self::new();
+
+        for value in arr {
+            ll.push_back(value.clone()).map_err(|(error, _)| error)?;
+        }
+
+        Ok(ll)
+    }
+}
+
+impl<T, const N: usize> IntoIterator for StaticLinkedList<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a StaticLinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<StaticLinkedList<U, N>>
+    for StaticLinkedList<T, N>
+{
+    fn eq(&self, other: &StaticLinkedList<U, N>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for StaticLinkedList<T, N> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]>
+    for StaticLinkedList<T, N>
+{
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.len() == M && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<Vec<U>> for StaticLinkedList<T, N> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
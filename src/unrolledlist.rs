@@ -0,0 +1,628 @@
+#![allow(dead_code)]
+
+use crate::linkedlist::{index_out_of_bounds, LinkedListError, Result};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::{FromIterator, IntoIterator};
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+/// The default number of values stored in each node of an unrolled linked list.
+pub const DEFAULT_CHUNK_SIZE: usize = 16;
+
+/// An iterator over the elements of an unrolled linked list.
+pub struct Iter<'a, T, const N: usize> {
+    /// An iterator over the values in the current node.
+    values: slice::Iter<'a, Option<T>>,
+    /// A reference to the node after the current one.
+    next_node: Option<&'a Node<T, N>>,
+}
+
+impl<'a, T, const N: usize> Iter<'a, T, N> {
+    /// Create an iterator from an unrolled linked list.
+    pub fn new(ll: &'a UnrolledList<T, N>) -> Self {
+        match ll.head.as_deref() {
+            Some(node) => Self {
+                values: node.slots().iter(),
+                next_node: node.next.as_deref(),
+            },
+            None => Self {
+                values: [].iter(),
+                next_node: None,
+            },
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                return value.as_ref();
+            }
+
+            let node = self.next_node?;
+            self.values = node.slots().iter();
+            self.next_node = node.next.as_deref();
+        }
+    }
+}
+
+/// An iterator over the elements of an unrolled linked list, allowing for them to be mutated.
+pub struct IterMut<'a, T, const N: usize> {
+    /// An iterator over the values in the current node.
+    values: slice::IterMut<'a, Option<T>>,
+    /// A reference to the node after the current one.
+    next_node: Option<&'a mut Node<T, N>>,
+}
+
+impl<'a, T, const N: usize> IterMut<'a, T, N> {
+    /// Create a mutable iterator from an unrolled linked list.
+    pub fn new(ll: &'a mut UnrolledList<T, N>) -> Self {
+        match ll.head.as_deref_mut() {
+            Some(node) => {
+                let (values, next_node) = node.parts_mut();
+
+                Self {
+                    values: values.iter_mut(),
+                    next_node,
+                }
+            }
+            None => Self {
+                values: [].iter_mut(),
+                next_node: None,
+            },
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                return value.as_mut();
+            }
+
+            let node = self.next_node.take()?;
+            let (values, next_node) = node.parts_mut();
+            self.values = values.iter_mut();
+            self.next_node = next_node;
+        }
+    }
+}
+
+/// An iterator that moves the elements out of an unrolled linked list.
+pub struct IntoIter<T, const N: usize>(UnrolledList<T, N>);
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+/// A node in an unrolled linked list, holding up to `N` values inline.
+#[derive(Clone, Debug)]
+struct Node<T, const N: usize> {
+    /// The node's storage. The values are held contiguously in the `len`
+    /// slots from `start`, and every other slot is empty.
+    values: [Option<T>; N],
+    /// The slot holding the node's first value.
+    start: usize,
+    /// The number of values in the node.
+    len: usize,
+    /// The next node in the linked list.
+    next: Option<Box<Node<T, N>>>,
+}
+
+impl<T, const N: usize> Node<T, N> {
+    /// Create an empty node.
+    fn new() -> Self {
+        Self {
+            values: core::array::from_fn(|_| None),
+            start: 0,
+            len: 0,
+            next: None,
+        }
+    }
+
+    /// Check if the node cannot hold any more values.
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Get the slots holding the node's values.
+    fn slots(&self) -> &[Option<T>] {
+        &self.values[self.start..self.start + self.len]
+    }
+
+    /// Get mutable references to the slots holding the node's values.
+    fn slots_mut(&mut self) -> &mut [Option<T>] {
+        &mut self.values[self.start..self.start + self.len]
+    }
+
+    /// Get mutable references to the slots holding the node's values and to
+    /// the next node.
+    fn parts_mut(&mut self) -> (&mut [Option<T>], Option<&mut Self>) {
+        (
+            &mut self.values[self.start..self.start + self.len],
+            self.next.as_deref_mut(),
+        )
+    }
+
+    /// Get a reference to the value at a given index within the node.
+    fn get(&self, index: usize) -> &T {
+        self.slots()[index].as_ref().unwrap()
+    }
+
+    /// Get a mutable reference to the value at a given index within the node.
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        self.slots_mut()[index].as_mut().unwrap()
+    }
+
+    /// Insert a value at a given index within the node, which must not be
+    /// full. The values after the index are shifted back, or the values
+    /// before it forward if there is no room at the back.
+    fn insert(&mut self, index: usize, value: T) {
+        if self.start + self.len == N {
+            self.values[self.start - 1..self.start + index].rotate_left(1);
+            self.start -= 1;
+        } else {
+            self.values[self.start + index..=self.start + self.len].rotate_right(1);
+        }
+
+        self.values[self.start + index] = Some(value);
+        self.len += 1;
+    }
+
+    /// Remove the value at a given index within the node, shifting whichever
+    /// side of it is shorter to close the gap.
+    fn remove(&mut self, index: usize) -> T {
+        let value = self.values[self.start + index].take().unwrap();
+
+        if index < self.len / 2 {
+            self.values[self.start..=self.start + index].rotate_right(1);
+            self.start += 1;
+        } else {
+            self.values[self.start + index..self.start + self.len].rotate_left(1);
+        }
+
+        self.len -= 1;
+
+        if self.len == 0 {
+            self.start = 0;
+        }
+
+        value
+    }
+
+    /// Move the second half of the node's values into a new node directly after it.
+    fn split(&mut self) {
+        let mut new_node = Self::new();
+        let half = self.len / 2;
+
+        for (slot, value) in new_node
+            .values
+            .iter_mut()
+            .zip(&mut self.slots_mut()[half..])
+        {
+            *slot = value.take();
+        }
+
+        new_node.len = self.len - half;
+        self.len = half;
+        new_node.next = self.next.take();
+        self.next = Some(Box::new(new_node));
+    }
+
+    /// Merge the next node into this one if the node is less than half full
+    /// and both nodes' values fit in a single node.
+    fn merge_next(&mut self) {
+        if self.len < N / 2 {
+            if let Some(next) = self.next.as_mut() {
+                if self.len + next.len <= N {
+                    let mut next = self.next.take().unwrap();
+                    self.values.rotate_left(self.start);
+                    self.start = 0;
+
+                    for (slot, value) in self.values[self.len..].iter_mut().zip(next.slots_mut()) {
+                        *slot = value.take();
+                    }
+
+                    self.len += next.len;
+                    self.next = next.next.take();
+                }
+            }
+        }
+    }
+}
+
+/// An unrolled linked list, storing up to `N` values in each node.
+///
+/// Each node keeps its values in an inline `[Option<T>; N]` rather than an
+/// array of uninitialised slots, since the crate forbids unsafe code. Every
+/// slot therefore carries the `Option` discriminant, which costs extra space
+/// for types without a niche, and a node starts with all `N` slots set to
+/// `None`.
+#[derive(Clone, Debug)]
+pub struct UnrolledList<T, const N: usize = DEFAULT_CHUNK_SIZE> {
+    /// The first node in the linked list.
+    head: Option<Box<Node<T, N>>>,
+    /// The total number of values in the linked list.
+    size: usize,
+}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    /// Create an empty unrolled linked list.
+    pub fn new() -> Self {
+        const { assert!(N >= 2, "unrolled list nodes must hold at least two values") };
+
+        Self {
+            head: None,
+            size: 0,
+        }
+    }
+
+    /// Get the number of values in the linked list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Check if the linked list contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get the number of nodes in the linked list.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut current = self.head.as_deref();
+
+        while let Some(node) = current {
+            count += 1;
+            current = node.next.as_deref();
+        }
+
+        count
+    }
+
+    /// Find the node containing a value by index, returning the node and the
+    /// value's index within it. The index must be in bounds.
+    fn find(&self, mut index: usize) -> (&Node<T, N>, usize) {
+        let mut current = self.head.as_deref().unwrap();
+
+        while index >= current.len {
+            index -= current.len;
+            current = current.next.as_deref().unwrap();
+        }
+
+        (current, index)
+    }
+
+    /// Find the node containing a value by index, returning a mutable
+    /// reference to the node and the value's index within it. The index must
+    /// be in bounds.
+    fn find_mut(&mut self, mut index: usize) -> (&mut Node<T, N>, usize) {
+        let mut current = self.head.as_deref_mut().unwrap();
+
+        while index >= current.len {
+            index -= current.len;
+            current = current.next.as_deref_mut().unwrap();
+        }
+
+        (current, index)
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get_value(&self, index: usize) -> Result<&T> {
+        if index < self.size {
+            let (node, index) = self.find(index);
+
+            Ok(node.get(index))
+        } else {
+            Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            })
+        }
+    }
+
+    /// Get a mutable reference to the value at a given index.
+    pub fn get_value_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index < self.size {
+            let (node, index) = self.find_mut(index);
+
+            Ok(node.get_mut(index))
+        } else {
+            Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            })
+        }
+    }
+
+    /// Set the value at a given index.
+    pub fn set_value(&mut self, index: usize, value: T) -> Result<()> {
+        *self.get_value_mut(index)? = value;
+
+        Ok(())
+    }
+
+    /// Insert a value at a given index in the linked list, splitting the
+    /// containing node if it is full.
+    pub fn push(&mut self, mut index: usize, value: T) -> Result<()> {
+        if index > self.size {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            });
+        }
+
+        if self.head.is_none() {
+            self.head = Some(Box::new(Node::new()));
+        }
+
+        let mut current = self.head.as_deref_mut().unwrap();
+
+        while index > current.len {
+            index -= current.len;
+            current = current.next.as_deref_mut().unwrap();
+        }
+
+        if current.is_full() {
+            current.split();
+
+            if index > current.len {
+                index -= current.len;
+                current = current.next.as_deref_mut().unwrap();
+            }
+        }
+
+        current.insert(index, value);
+        self.size += 1;
+
+        Ok(())
+    }
+
+    /// Insert a value at the start of the linked list.
+    pub fn push_front(&mut self, value: T) {
+        self.push(0, value).unwrap();
+    }
+
+    /// Insert a value at the end of the linked list.
+    pub fn push_back(&mut self, value: T) {
+        self.push(self.size, value).unwrap();
+    }
+
+    /// Remove the value at a given index, returning the owned value. Nodes
+    /// that fall below half capacity are merged with the node after them.
+    pub fn pop(&mut self, mut index: usize) -> Result<T> {
+        if index >= self.size {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                size: self.size,
+            });
+        }
+
+        let mut link = &mut self.head;
+
+        while index >= link.as_ref().unwrap().len {
+            index -= link.as_ref().unwrap().len;
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        let node = link.as_mut().unwrap();
+        let value = node.remove(index);
+
+        if node.len == 0 {
+            let next = node.next.take();
+            *link = next;
+        } else {
+            node.merge_next();
+        }
+
+        self.size -= 1;
+
+        Ok(value)
+    }
+
+    /// Remove the first value, returning the owned value.
+    pub fn pop_front(&mut self) -> Result<T> {
+        self.pop(0)
+    }
+
+    /// Remove the last value, returning the owned value.
+    pub fn pop_back(&mut self) -> Result<T> {
+        match self.size {
+            0 => Err(LinkedListError::IndexOutOfBounds { index: 0, size: 0 }),
+            size => self.pop(size - 1),
+        }
+    }
+
+    /// Clear the linked list.
+    pub fn clear(&mut self) {
+        self.head = None;
+        self.size = 0;
+    }
+
+    /// Reverse the elements in the linked list in place.
+    pub fn reverse(&mut self) {
+        let mut current = self.head.take();
+
+        while let Some(mut node) = current {
+            current = node.next.take();
+            node.slots_mut().reverse();
+            node.next = self.head.take();
+            self.head = Some(node);
+        }
+    }
+
+    /// Returns an iterator over the elements in the linked list.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over the elements in the linked list, allowing for them to be mutated.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut::new(self)
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for UnrolledList<T, N> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get_value(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for UnrolledList<T, N> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_value_mut(index)
+            .unwrap_or_else(|_| index_out_of_bounds(index, len))
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T]> for UnrolledList<T, N> {
+    fn from(arr: &[T]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone, const N: usize> From<&mut [T]> for UnrolledList<T, N> {
+    fn from(arr: &mut [T]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone, const N: usize, const M: usize> From<&[T; M]> for UnrolledList<T, N> {
+    fn from(arr: &[T; M]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone, const N: usize, const M: usize> From<&mut [T; M]> for UnrolledList<T, N> {
+    fn from(arr: &mut [T; M]) -> Self {
+        arr.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<[T; M]> for UnrolledList<T, N> {
+    fn from(arr: [T; M]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for UnrolledList<T, N> {
+    fn from(vector: Vec<T>) -> Self {
+        vector.into_iter().collect()
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize, const M: usize> TryInto<[T; M]> for UnrolledList<T, N> {
+    type Error = LinkedListError;
+
+    fn try_into(self) -> core::result::Result<[T; M], Self::Error> {
+        if self.size == M {
+            let vector: Vec<_> = self.into();
+            Ok(vector.try_into().unwrap())
+        } else {
+            Err(LinkedListError::InvalidArraySize {
+                size: self.size,
+                array_size: M,
+            })
+        }
+    }
+}
+
+impl<T, const N: usize> From<UnrolledList<T, N>> for Vec<T> {
+    fn from(ll: UnrolledList<T, N>) -> Self {
+        let mut vector = Vec::with_capacity(ll.size);
+        let mut current = ll.head;
+
+        while let Some(mut node) = current {
+            vector.extend(node.slots_mut().iter_mut().filter_map(Option::take));
+            current = node.next.take();
+        }
+
+        vector
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    /// Collect values into full nodes, filling each one before starting the next.
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut ll = Self::new();
+        let mut link = &mut ll.head;
+        let mut iter = iter.into_iter().peekable();
+
+        while iter.peek().is_some() {
+            let mut node = Node::new();
+
+            for (slot, value) in node.values.iter_mut().zip(iter.by_ref()) {
+                *slot = Some(value);
+                node.len += 1;
+            }
+
+            ll.size += node.len;
+            link = &mut link.insert(Box::new(node)).next;
+        }
+
+        ll
+    }
+}
+
+impl<T, const N: usize> IntoIterator for UnrolledList<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<UnrolledList<U, N>> for UnrolledList<T, N> {
+    fn eq(&self, other: &UnrolledList<U, N>) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for UnrolledList<T, N> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.size == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for UnrolledList<T, N> {
+    fn eq(&self, other: &[U; M]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<Vec<U>> for UnrolledList<T, N> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}